@@ -0,0 +1,280 @@
+use std::{
+	fs,
+	io::{BufRead, Write},
+};
+
+use anyhow::{Result, bail};
+use demon_bluff_gameplay_engine::{
+	game_state::{Action, DrawStats, GameState, GameStateMutationResult},
+	notation::Transcript,
+	replay::Replay,
+	rule_set::RuleSet,
+	villager::VillagerArchetype,
+};
+use demon_bluff_logic_engine::{RevealStrategy, predict};
+use serde::{Deserialize, Serialize};
+
+const HELP: &str = "\
+Commands:
+  Reveal #N <Role> {testimony}                   a villager was revealed
+  Kill #N => <true identity|claimed>[, corrupted]  a villager was executed
+  Ability #N {testimony} slays #M => <result>      a villager's ability was used
+  Lilis kills #N|nobody                          the demon's night kill
+  undo                                           take back the last action
+  redo                                           put back the last action taken back
+  show                                           print the game so far
+  save <path>                                    save the session as JSON
+  replay <path>                                  save the game as a replay for bug reports
+  strategy <Simple|FollowTestimony|InformationGain>  how to pick what to reveal next
+  help                                           print this
+  exit                                           quit
+Testimonies are written the way they are printed, e.g. {(#2 is good) && (#5 is evil)}";
+
+/// What gets saved and loaded. The state is rebuilt by replaying the transcript so the two can't disagree
+#[derive(Serialize, Deserialize)]
+struct SavedSession {
+	transcript: String,
+	// sessions saved before the strategy could be picked used the simple one
+	#[serde(default = "simple_reveal_strategy")]
+	reveal_strategy: RevealStrategy,
+}
+
+struct Session {
+	transcript: Transcript,
+	state: GameState,
+	result: GameStateMutationResult,
+	reveal_strategy: RevealStrategy,
+}
+
+impl Session {
+	fn new(mut transcript: Transcript, reveal_strategy: RevealStrategy) -> Result<Self> {
+		let (state, result) = transcript.replay()?;
+		// from here on the actions are tracked by the state's history
+		transcript.actions.clear();
+		Ok(Self {
+			transcript,
+			state,
+			result,
+			reveal_strategy,
+		})
+	}
+
+	fn apply(&mut self, action: Action) -> Result<()> {
+		self.result = self.state.mutate(action)?;
+		Ok(())
+	}
+
+	fn undo(&mut self) -> bool {
+		// the game can't have ended before the last action
+		self.result = GameStateMutationResult::Continue;
+		self.state.undo().is_some()
+	}
+
+	fn redo(&mut self) -> bool {
+		match self.state.redo() {
+			Some(result) => {
+				self.result = result;
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// The deck and draw the session started with, followed by every action still in the [`GameState`]'s history
+	fn transcript(&self) -> Transcript {
+		Transcript {
+			actions: self.state.history().cloned().collect(),
+			..self.transcript.clone()
+		}
+	}
+
+	fn save(&self, path: &str) -> Result<()> {
+		fs::write(
+			path,
+			serde_json::to_string_pretty(&SavedSession {
+				transcript: self.transcript().to_string(),
+				reveal_strategy: self.reveal_strategy,
+			})?,
+		)?;
+		Ok(())
+	}
+}
+
+/// Plays along with a live game typed in by hand, printing what to do after every step.
+/// Resumes the session saved at `session_path` if there is one
+pub fn run(session_path: Option<&str>) -> Result<()> {
+	let stdin = std::io::stdin();
+	let mut stdout = std::io::stdout();
+	let mut lines = stdin.lock().lines();
+
+	let mut session = match session_path {
+		Some(path) => {
+			let saved: SavedSession = serde_json::from_str(&fs::read_to_string(path)?)?;
+			Session::new(saved.transcript.parse()?, saved.reveal_strategy)?
+		}
+		None => {
+			let mut prompt = |text: &str| -> Result<String> {
+				write!(stdout, "{}: ", text)?;
+				stdout.flush()?;
+				Ok(lines.next().transpose()?.unwrap_or_default())
+			};
+
+			let deck = prompt("Deck (e.g. Lover, Confessor, Minion)")?
+				.split(',')
+				.map(|archetype| archetype.trim().parse())
+				.collect::<Result<Vec<VillagerArchetype>, _>>()?;
+			let draw_stats: DrawStats =
+				prompt("Draw (e.g. 4 villagers, 0 outcasts, 1 minions, 0 demons)")?
+					.trim()
+					.parse()?;
+			let total_evils = prompt("Evils")?.trim().parse()?;
+			let night_effects_active = prompt("Night effects (true/false)")?.trim().parse()?;
+			let rules = prompt("Rules (blank for current)")?;
+			let rule_set = match rules.trim() {
+				"" => RuleSet::current(),
+				name => match RuleSet::named(name) {
+					Some(rule_set) => rule_set,
+					None => bail!("Unknown rules {}", name),
+				},
+			};
+			let strategy = prompt("Reveal strategy (blank for Simple)")?;
+			let reveal_strategy = match strategy.trim() {
+				"" => RevealStrategy::Simple,
+				name => match parse_reveal_strategy(name) {
+					Some(reveal_strategy) => reveal_strategy,
+					None => bail!("Unknown reveal strategy {}", name),
+				},
+			};
+
+			Session::new(
+				Transcript {
+					deck,
+					draw_stats,
+					total_evils,
+					night_effects_active,
+					rule_set,
+					actions: Vec::new(),
+				},
+				reveal_strategy,
+			)?
+		}
+	};
+
+	writeln!(stdout, "{}", HELP)?;
+	print_prediction(&mut stdout, &session)?;
+	loop {
+		write!(stdout, "> ")?;
+		stdout.flush()?;
+		let Some(line) = lines.next().transpose()? else {
+			return Ok(());
+		};
+
+		let line = line.trim();
+		match line.split_once(' ').map_or(line, |(command, _)| command) {
+			"" => continue,
+			"exit" => return Ok(()),
+			"help" => writeln!(stdout, "{}", HELP)?,
+			"show" => write!(stdout, "{}", session.transcript())?,
+			"undo" => {
+				if session.undo() {
+					print_prediction(&mut stdout, &session)?;
+				} else {
+					writeln!(stdout, "Nothing to undo")?;
+				}
+			}
+			"redo" => {
+				if session.redo() {
+					print_prediction(&mut stdout, &session)?;
+				} else {
+					writeln!(stdout, "Nothing to redo")?;
+				}
+			}
+			"save" => match line.strip_prefix("save ") {
+				Some(path) => match session.save(path.trim()) {
+					Ok(()) => writeln!(stdout, "Saved to {}", path.trim())?,
+					Err(error) => writeln!(stdout, "Could not save: {}", error)?,
+				},
+				None => writeln!(stdout, "Usage: save <path>")?,
+			},
+			"replay" => match line.strip_prefix("replay ") {
+				Some(path) => match Replay::from_state(&session.state)
+					.to_json()
+					.map_err(anyhow::Error::from)
+					.and_then(|json| Ok(fs::write(path.trim(), json)?))
+				{
+					Ok(()) => writeln!(stdout, "Replay saved to {}", path.trim())?,
+					Err(error) => writeln!(stdout, "Could not save the replay: {}", error)?,
+				},
+				None => writeln!(stdout, "Usage: replay <path>")?,
+			},
+			"strategy" => match line
+				.strip_prefix("strategy ")
+				.and_then(parse_reveal_strategy)
+			{
+				Some(reveal_strategy) => {
+					session.reveal_strategy = reveal_strategy;
+					print_prediction(&mut stdout, &session)?;
+				}
+				None => writeln!(
+					stdout,
+					"Usage: strategy <Simple|FollowTestimony|InformationGain>"
+				)?,
+			},
+			_ => {
+				if session.result != GameStateMutationResult::Continue {
+					writeln!(stdout, "The game is over, undo to keep going")?;
+					continue;
+				}
+
+				let applied = line
+					.parse::<Action>()
+					.map_err(anyhow::Error::from)
+					.and_then(|action| session.apply(action));
+				match applied {
+					Ok(()) => print_prediction(&mut stdout, &session)?,
+					Err(error) => writeln!(stdout, "Rejected: {}", error)?,
+				}
+			}
+		}
+	}
+}
+
+fn print_prediction(stdout: &mut impl Write, session: &Session) -> Result<()> {
+	match session.result {
+		GameStateMutationResult::Win => {
+			writeln!(
+				stdout,
+				"Game won with {} HP left",
+				session.state.hitpoints()
+			)?;
+			return Ok(());
+		}
+		GameStateMutationResult::Loss => {
+			writeln!(stdout, "Game lost")?;
+			return Ok(());
+		}
+		GameStateMutationResult::Continue => {}
+	}
+
+	writeln!(stdout, "HP: {}", session.state.hitpoints())?;
+	match predict(&log::logger(), &session.state, session.reveal_strategy) {
+		Ok(actions) => {
+			let mut actions: Vec<String> =
+				actions.iter().map(|action| action.to_string()).collect();
+			actions.sort();
+			writeln!(stdout, "Recommended: {}", actions.join(" or "))?;
+		}
+		Err(error) => writeln!(stdout, "Prediction failed: {}", error)?,
+	}
+
+	Ok(())
+}
+
+fn simple_reveal_strategy() -> RevealStrategy {
+	RevealStrategy::Simple
+}
+
+/// Strategies are named the way they're saved
+fn parse_reveal_strategy(name: &str) -> Option<RevealStrategy> {
+	serde_json::from_value(serde_json::Value::String(name.trim().to_string())).ok()
+}
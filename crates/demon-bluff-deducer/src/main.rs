@@ -6,7 +6,10 @@ use std::{
 };
 
 use anyhow::Result;
-use demon_bluff_gameplay_engine::villager::{GoodVillager, VillagerArchetype};
+use demon_bluff_gameplay_engine::{
+	roles::RoleRegistry,
+	villager::{GoodVillager, VillagerArchetype},
+};
 use image::ColorType;
 use ocrs::{ImageSource, OcrEngine, OcrEngineParams};
 use rten::Model;
@@ -14,6 +17,8 @@ use rustautogui::{MatchMode, RustAutoGui, errors::AutoGuiError};
 use thiserror::Error;
 use xcap::{Monitor, Window};
 
+mod interactive;
+
 const IMAGE_MATCH_PRECISION: f32 = 0.5;
 
 // TODO: Resolution specific regions for reading text
@@ -44,6 +49,21 @@ fn load_image(
 }
 
 fn main() -> Result<()> {
+	// a rules file patching role definitions, for game updates the builtin rules haven't caught up with
+	if let Some(rules_path) = std::env::var_os("DEMON_BLUFF_RULES") {
+		RoleRegistry::builtin()
+			.with_rules(&std::fs::read_to_string(rules_path)?)?
+			.install()?;
+	}
+
+	// `ocr` runs the screen reading experiment, otherwise the argument is a saved session to resume
+	match std::env::args().nth(1).as_deref() {
+		Some("ocr") => ocr(),
+		session_path => interactive::run(session_path),
+	}
+}
+
+fn ocr() -> Result<()> {
 	let window = Window::all()
 		.unwrap()
 		.into_iter()
@@ -1,14 +1,16 @@
 use std::fmt::Display;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{Expression, testimony::Direction, villager::VillagerIndex};
 
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct VillagerAffect {
 	direction: Direction,
 	distance: u8,
 }
 
-#[derive(Debug, Eq, PartialEq, Display, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Display, Hash, Serialize, Deserialize)]
 pub enum NightEffect {
 	KillUnrevealed,
 }
@@ -0,0 +1,163 @@
+use thiserror::Error;
+
+use crate::{game_state::DrawStats, villager::VillagerArchetype};
+
+/// Why a deck can't be played. Any deck passing [`validate_deck`] is accepted, duplicated archetypes and board sizes outside the presets included
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DeckError {
+	#[error("The DrawStats don't draw any villagers")]
+	EmptyBoard,
+	#[error("The deck has {available} {kind} but {drawn} are drawn")]
+	NotEnoughArchetypes {
+		kind: &'static str,
+		drawn: usize,
+		available: usize,
+	},
+	#[error("{archetype} can't be in the deck without {prerequisite}")]
+	MissingPrerequisite {
+		archetype: VillagerArchetype,
+		prerequisite: VillagerArchetype,
+	},
+	#[error("{total_evils} evils is fewer than the {drawn} minions and demons drawn")]
+	TooFewEvils { total_evils: usize, drawn: usize },
+	#[error("{total_evils} evils don't fit on a board of {total_villagers} villagers")]
+	TooManyEvils {
+		total_evils: usize,
+		total_villagers: usize,
+	},
+}
+
+/// Checks a deck can deal a board with the given [`DrawStats`] and number of evils
+pub fn validate_deck(
+	deck: &[VillagerArchetype],
+	draw_stats: &DrawStats,
+	total_evils: usize,
+) -> Result<(), DeckError> {
+	let total_villagers = draw_stats.total_villagers();
+	if total_villagers == 0 {
+		return Err(DeckError::EmptyBoard);
+	}
+
+	let available = |filter: fn(&VillagerArchetype) -> bool| {
+		deck.iter().filter(|archetype| filter(archetype)).count()
+	};
+	for (kind, drawn, available) in [
+		(
+			"villagers",
+			draw_stats.villagers(),
+			available(|archetype| matches!(archetype, VillagerArchetype::GoodVillager(_))),
+		),
+		(
+			"outcasts",
+			draw_stats.outcasts(),
+			available(|archetype| matches!(archetype, VillagerArchetype::Outcast(_))),
+		),
+		(
+			"minions",
+			draw_stats.minions(),
+			available(|archetype| matches!(archetype, VillagerArchetype::Minion(_))),
+		),
+		(
+			"demons",
+			draw_stats.demons(),
+			available(|archetype| matches!(archetype, VillagerArchetype::Demon(_))),
+		),
+	] {
+		if available < drawn {
+			return Err(DeckError::NotEnoughArchetypes {
+				kind,
+				drawn,
+				available,
+			});
+		}
+	}
+
+	for archetype in deck {
+		let prerequisite = archetype.deck_prerequisite();
+		if !deck.contains(&prerequisite) {
+			return Err(DeckError::MissingPrerequisite {
+				archetype: archetype.clone(),
+				prerequisite,
+			});
+		}
+	}
+
+	let drawn_evils = draw_stats.minions() + draw_stats.demons();
+	if total_evils < drawn_evils {
+		return Err(DeckError::TooFewEvils {
+			total_evils,
+			drawn: drawn_evils,
+		});
+	}
+
+	if total_evils > total_villagers {
+		return Err(DeckError::TooManyEvils {
+			total_evils,
+			total_villagers,
+		});
+	}
+
+	Ok(())
+}
+
+#[test]
+fn test_validate_deck() {
+	use crate::villager::{Demon, GoodVillager, Minion, Outcast};
+
+	// duplicates and a board bigger than any preset are fine
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Knight),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Enlightened),
+		VillagerArchetype::Outcast(Outcast::Drunk),
+		VillagerArchetype::Outcast(Outcast::Wretch),
+		VillagerArchetype::Minion(Minion::Twinion),
+		VillagerArchetype::Minion(Minion::Twinion),
+		VillagerArchetype::Minion(Minion::Puppeteer),
+		VillagerArchetype::Demon(Demon::Pooka),
+	];
+	assert_eq!(Ok(()), validate_deck(&deck, &DrawStats::new(7, 2, 3, 1), 4));
+
+	assert_eq!(
+		Err(DeckError::EmptyBoard),
+		validate_deck(&deck, &DrawStats::new(0, 0, 0, 0), 0)
+	);
+	assert_eq!(
+		Err(DeckError::NotEnoughArchetypes {
+			kind: "outcasts",
+			drawn: 3,
+			available: 2
+		}),
+		validate_deck(&deck, &DrawStats::new(6, 3, 3, 1), 4)
+	);
+	assert_eq!(
+		Err(DeckError::TooFewEvils {
+			total_evils: 3,
+			drawn: 4
+		}),
+		validate_deck(&deck, &DrawStats::new(7, 2, 3, 1), 3)
+	);
+	assert_eq!(
+		Err(DeckError::TooManyEvils {
+			total_evils: 4,
+			total_villagers: 3
+		}),
+		validate_deck(&deck, &DrawStats::new(1, 0, 1, 1), 4)
+	);
+
+	let mut puppet_deck = deck.clone();
+	puppet_deck.retain(|archetype| *archetype != VillagerArchetype::Minion(Minion::Puppeteer));
+	puppet_deck.push(VillagerArchetype::Minion(Minion::Puppet));
+	assert_eq!(
+		Err(DeckError::MissingPrerequisite {
+			archetype: VillagerArchetype::Minion(Minion::Puppet),
+			prerequisite: VillagerArchetype::Minion(Minion::Puppeteer)
+		}),
+		validate_deck(&puppet_deck, &DrawStats::new(7, 2, 3, 1), 4)
+	);
+}
@@ -5,7 +5,9 @@ use thiserror::Error;
 
 use crate::{
 	Expression,
-	testimony::{self, Testimony},
+	deck::{DeckError, validate_deck},
+	rule_set::RuleSet,
+	testimony::{self, Testimony, TestimonyError},
 	villager::{
 		ActiveVillager, ConfirmedVillager, Demon, ExecutionResult, GoodVillager, HiddenVillager,
 		Minion, Outcast, Villager, VillagerArchetype, VillagerIndex, VillagerInstance,
@@ -22,7 +24,7 @@ pub enum DayCycle {
 	Night,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DrawStats {
 	villagers: usize,
 	outcasts: usize,
@@ -40,52 +42,76 @@ pub struct GameState {
 	reveal_order: Vec<VillagerIndex>,
 	hitpoints: u8,
 	total_evils: usize,
+	/// Saves from before rule sets were recorded were played under the current rules
+	#[serde(default)]
+	rule_set: RuleSet,
+	#[serde(skip)]
+	history: Vec<HistoryEntry>,
+	#[serde(skip)]
+	undone: Vec<HistoryEntry>,
 }
 
+/// The parts of a [`GameState`] an [`Action`] can change
 #[derive(Debug, Clone)]
+struct Snapshot {
+	next_day: Option<u8>,
+	villagers: Vec<Villager>,
+	reveal_order: Vec<VillagerIndex>,
+	hitpoints: u8,
+}
+
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+	action: Action,
+	result: GameStateMutationResult,
+	// the state before the action while it is in the history, the state after it once it has been undone
+	other: Snapshot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RevealResult {
 	index: VillagerIndex,
 	instance: Option<VillagerInstance>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KillAttempt {
 	target: VillagerIndex,
 	result: Option<KillResult>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KillResult {
 	Unrevealed(UnrevealedKillData),
 	Revealed(KillData),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnrevealedKillData {
 	instance: VillagerInstance,
 	inner: KillData,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KillData {
 	true_identity: Option<VillagerArchetype>,
 	corrupted: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlayerKill {
 	target: VillagerIndex,
 	result: KillResult,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AbilityResult {
 	source: VillagerIndex,
 	testimony: Option<Expression<Testimony>>,
 	slayer_kill: Option<SlayerKill>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Action {
 	TryReveal(RevealResult),
 	TryExecute(KillAttempt),
@@ -96,14 +122,171 @@ pub enum Action {
 impl Display for Action {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
-			Self::TryReveal(reveal_result) => write!(f, "Reveal {}", reveal_result.index),
-			Self::TryExecute(kill_attempt) => write!(f, "Kill {}", kill_attempt.target),
-			Self::Ability(ability_result) => write!(f, "Ability {}", ability_result.source),
-			Self::LilisNightKill(_) => todo!(),
+			Self::TryReveal(reveal_result) => {
+				write!(f, "Reveal {}", reveal_result.index)?;
+				if let Some(instance) = &reveal_result.instance {
+					write!(f, " ")?;
+					write_instance(f, instance)?;
+				}
+
+				Ok(())
+			}
+			Self::TryExecute(kill_attempt) => {
+				write!(f, "Kill {}", kill_attempt.target)?;
+				match &kill_attempt.result {
+					Some(result) => write!(f, " => {}", result),
+					None => Ok(()),
+				}
+			}
+			Self::Ability(ability_result) => {
+				write!(f, "Ability {}", ability_result.source)?;
+				if let Some(testimony) = &ability_result.testimony {
+					write!(f, " {{{}}}", testimony)?;
+				}
+
+				match &ability_result.slayer_kill {
+					Some(slayer_kill) => {
+						write!(f, " slays {} => {}", slayer_kill.target, slayer_kill.result)
+					}
+					None => Ok(()),
+				}
+			}
+			Self::LilisNightKill(target) => match target {
+				Some(target) => write!(f, "Lilis kills {}", target),
+				None => write!(f, "Lilis kills nobody"),
+			},
+		}
+	}
+}
+
+impl Action {
+	/// Checks every villager the action and its testimonies mention is on a board of `total_villagers`
+	pub fn validate(&self, total_villagers: usize) -> Result<(), TestimonyError> {
+		match self {
+			Self::TryReveal(reveal_result) => {
+				reveal_result.index.validate(total_villagers)?;
+				match &reveal_result.instance {
+					Some(instance) => validate_instance(instance, total_villagers),
+					None => Ok(()),
+				}
+			}
+			Self::TryExecute(kill_attempt) => {
+				kill_attempt.target.validate(total_villagers)?;
+				match &kill_attempt.result {
+					Some(result) => result.validate(total_villagers),
+					None => Ok(()),
+				}
+			}
+			Self::Ability(ability_result) => {
+				ability_result.source.validate(total_villagers)?;
+				if let Some(testimony) = &ability_result.testimony {
+					Testimony::validate_expression(testimony, total_villagers)?;
+				}
+
+				match &ability_result.slayer_kill {
+					Some(slayer_kill) => {
+						slayer_kill.target.validate(total_villagers)?;
+						slayer_kill.result.validate(total_villagers)
+					}
+					None => Ok(()),
+				}
+			}
+			Self::LilisNightKill(target) => match target {
+				Some(target) => target.validate(total_villagers),
+				None => Ok(()),
+			},
+		}
+	}
+}
+
+impl KillResult {
+	fn validate(&self, total_villagers: usize) -> Result<(), TestimonyError> {
+		match self {
+			Self::Unrevealed(unrevealed_kill_data) => {
+				validate_instance(&unrevealed_kill_data.instance, total_villagers)
+			}
+			Self::Revealed(_) => Ok(()),
 		}
 	}
 }
 
+fn validate_testimony(
+	archetype: &VillagerArchetype,
+	index: &VillagerIndex,
+	testimony: &Expression<Testimony>,
+	total_villagers: usize,
+) -> Result<(), GameStateMutationError> {
+	if Testimony::matches_archetype(testimony, archetype, index, total_villagers) {
+		Ok(())
+	} else {
+		Err(GameStateMutationError::TestimonyDoesNotMatchArchetype {
+			archetype: archetype.clone(),
+			testimony: testimony.clone(),
+		})
+	}
+}
+
+fn validate_revealed_testimony(
+	instance: &VillagerInstance,
+	index: &VillagerIndex,
+	total_villagers: usize,
+) -> Result<(), GameStateMutationError> {
+	match instance.testimony() {
+		Some(testimony) => {
+			validate_testimony(instance.archetype(), index, testimony, total_villagers)
+		}
+		None => Ok(()),
+	}
+}
+
+fn validate_instance(
+	instance: &VillagerInstance,
+	total_villagers: usize,
+) -> Result<(), TestimonyError> {
+	match instance.testimony() {
+		Some(testimony) => Testimony::validate_expression(testimony, total_villagers),
+		None => Ok(()),
+	}
+}
+
+impl Display for KillResult {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let kill_data = match self {
+			Self::Revealed(kill_data) => kill_data,
+			Self::Unrevealed(unrevealed_kill_data) => &unrevealed_kill_data.inner,
+		};
+
+		// the true identity is only known if it differs from what the villager claimed
+		match &kill_data.true_identity {
+			Some(true_identity) => write!(f, "{}", true_identity)?,
+			None => write!(f, "claimed")?,
+		}
+
+		if kill_data.corrupted {
+			write!(f, ", corrupted")?;
+		}
+
+		if let Self::Unrevealed(unrevealed_kill_data) = self {
+			write!(f, ", unrevealed ")?;
+			write_instance(f, &unrevealed_kill_data.instance)?;
+		}
+
+		Ok(())
+	}
+}
+
+fn write_instance(
+	f: &mut std::fmt::Formatter<'_>,
+	instance: &VillagerInstance,
+) -> std::fmt::Result {
+	write!(f, "{}", instance.archetype())?;
+	if let Some(testimony) = instance.testimony() {
+		write!(f, " {{{}}}", testimony)?;
+	}
+
+	Ok(())
+}
+
 #[derive(Error, Debug)]
 pub enum GameStateInitError {
 	#[error("Provided villager count does not match DrawStats")]
@@ -144,13 +327,22 @@ pub enum GameStateMutationError {
 	CannotReplaceTestimony,
 	#[error("Trying to reveal a card that is disallowed by the deck")]
 	InvalidReveal,
+	#[error("Only the last {0} reveals can be blocked")]
+	RevealCannotBeBlocked(usize),
 	#[error("A villager with an action cannot be revealed with a testimony")]
 	RevealActionAndTestimony,
 	#[error("A villager without an action cannot be revealed without a testimony")]
 	RevealNoActionNorTestimony,
+	#[error("{0}")]
+	Testimony(#[from] TestimonyError),
+	#[error("A {archetype} can't testify {testimony}")]
+	TestimonyDoesNotMatchArchetype {
+		archetype: VillagerArchetype,
+		testimony: Expression<Testimony>,
+	},
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameStateMutationResult {
 	Win,
 	Loss,
@@ -171,6 +363,10 @@ impl KillAttempt {
 	pub fn target(&self) -> &VillagerIndex {
 		&self.target
 	}
+
+	pub fn result(&self) -> &Option<KillResult> {
+		&self.result
+	}
 }
 
 impl KillData {
@@ -189,18 +385,42 @@ impl KillData {
 			corrupted,
 		})
 	}
+
+	pub fn true_identity(&self) -> &Option<VillagerArchetype> {
+		&self.true_identity
+	}
+
+	pub fn corrupted(&self) -> bool {
+		self.corrupted
+	}
 }
 
 impl UnrevealedKillData {
 	pub fn new(instance: VillagerInstance, inner: KillData) -> Self {
 		Self { instance, inner }
 	}
+
+	pub fn instance(&self) -> &VillagerInstance {
+		&self.instance
+	}
+
+	pub fn inner(&self) -> &KillData {
+		&self.inner
+	}
 }
 
 impl SlayerKill {
 	pub fn new(target: VillagerIndex, result: KillResult) -> Self {
 		Self { target, result }
 	}
+
+	pub fn target(&self) -> &VillagerIndex {
+		&self.target
+	}
+
+	pub fn result(&self) -> &KillResult {
+		&self.result
+	}
 }
 
 impl AbilityResult {
@@ -219,6 +439,14 @@ impl AbilityResult {
 	pub fn source(&self) -> &VillagerIndex {
 		&self.source
 	}
+
+	pub fn testimony(&self) -> &Option<Expression<Testimony>> {
+		&self.testimony
+	}
+
+	pub fn slayer_kill(&self) -> &Option<SlayerKill> {
+		&self.slayer_kill
+	}
 }
 
 impl RevealResult {
@@ -229,6 +457,20 @@ impl RevealResult {
 	pub fn index(&self) -> &VillagerIndex {
 		&self.index
 	}
+
+	pub fn instance(&self) -> &Option<VillagerInstance> {
+		&self.instance
+	}
+}
+
+impl Display for DrawStats {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{} villagers, {} outcasts, {} minions, {} demons",
+			self.villagers, self.outcasts, self.minions, self.demons
+		)
+	}
 }
 
 impl DrawStats {
@@ -297,6 +539,9 @@ impl GameState {
 			reveal_order,
 			hitpoints,
 			total_evils,
+			rule_set: RuleSet::current(),
+			history: Vec::new(),
+			undone: Vec::new(),
 		})
 	}
 
@@ -340,6 +585,20 @@ impl GameState {
 		self.total_evils
 	}
 
+	pub fn hitpoints(&self) -> u8 {
+		self.hitpoints
+	}
+
+	pub fn rule_set(&self) -> &RuleSet {
+		&self.rule_set
+	}
+
+	/// Plays the game under a different [`RuleSet`] than the current one
+	pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+		self.rule_set = rule_set;
+		self
+	}
+
 	pub fn draw_stats(&self) -> &DrawStats {
 		&self.draw_stats
 	}
@@ -360,6 +619,14 @@ impl GameState {
 		self.next_day.is_some()
 	}
 
+	/// Whether the next action has to be an [`Action::LilisNightKill`]. Anything else gets [`GameStateMutationError::MustTakeNightAction`]
+	pub fn must_take_night_action(&self) -> bool {
+		match self.next_day {
+			Some(next_day) => next_day > DAYS_BEFORE_NIGHT,
+			None => false,
+		}
+	}
+
 	pub fn witch_block_active(&self) -> bool {
 		for villager in &self.villagers {
 			match villager {
@@ -434,14 +701,98 @@ impl GameState {
 			.sum()
 	}
 
+	/// Applies the action and records it in the history. The state is left untouched if it fails
 	pub fn mutate(
 		&mut self,
 		action: Action,
 	) -> Result<GameStateMutationResult, GameStateMutationError> {
-		let must_be_night = match self.next_day {
-			Some(next_day) => next_day > DAYS_BEFORE_NIGHT,
-			None => false,
-		};
+		let before = self.snapshot();
+		match self.apply(action.clone()) {
+			Ok(result) => {
+				self.history.push(HistoryEntry {
+					action,
+					result,
+					other: before,
+				});
+				self.undone.clear();
+				Ok(result)
+			}
+			Err(error) => {
+				self.restore(before);
+				Err(error)
+			}
+		}
+	}
+
+	/// Every action applied so far, in order
+	pub fn history(&self) -> impl Iterator<Item = &Action> {
+		self.history.iter().map(|entry| &entry.action)
+	}
+
+	/// The result each action in the [`GameState::history`] had
+	pub fn history_results(&self) -> impl Iterator<Item = GameStateMutationResult> {
+		self.history.iter().map(|entry| entry.result)
+	}
+
+	/// The state from before the first action in the history, with no history of its own
+	pub fn initial_state(&self) -> GameState {
+		let mut initial_state = self.clone();
+		if let Some(first_entry) = self.history.first() {
+			initial_state.restore(first_entry.other.clone());
+		}
+
+		initial_state.history.clear();
+		initial_state.undone.clear();
+		initial_state
+	}
+
+	/// Restores the state from before the last action, returning that action
+	pub fn undo(&mut self) -> Option<&Action> {
+		let mut entry = self.history.pop()?;
+		entry.other = self.swap_snapshot(entry.other);
+		self.undone.push(entry);
+		self.undone.last().map(|entry| &entry.action)
+	}
+
+	/// Restores the state from after the last undone action, returning the result it had. Applying a new action clears what can be redone
+	pub fn redo(&mut self) -> Option<GameStateMutationResult> {
+		let mut entry = self.undone.pop()?;
+		entry.other = self.swap_snapshot(entry.other);
+		let result = entry.result;
+		self.history.push(entry);
+		Some(result)
+	}
+
+	fn snapshot(&self) -> Snapshot {
+		Snapshot {
+			next_day: self.next_day,
+			villagers: self.villagers.clone(),
+			reveal_order: self.reveal_order.clone(),
+			hitpoints: self.hitpoints,
+		}
+	}
+
+	fn restore(&mut self, snapshot: Snapshot) {
+		self.next_day = snapshot.next_day;
+		self.villagers = snapshot.villagers;
+		self.reveal_order = snapshot.reveal_order;
+		self.hitpoints = snapshot.hitpoints;
+	}
+
+	fn swap_snapshot(&mut self, snapshot: Snapshot) -> Snapshot {
+		Snapshot {
+			next_day: replace(&mut self.next_day, snapshot.next_day),
+			villagers: replace(&mut self.villagers, snapshot.villagers),
+			reveal_order: replace(&mut self.reveal_order, snapshot.reveal_order),
+			hitpoints: replace(&mut self.hitpoints, snapshot.hitpoints),
+		}
+	}
+
+	fn apply(&mut self, action: Action) -> Result<GameStateMutationResult, GameStateMutationError> {
+		let must_be_night = self.must_take_night_action();
+		let total_villagers = self.villagers.len();
+		action.validate(total_villagers)?;
+
 		let mut health_deduction = 0;
 		let mut reset_cant_kills = false;
 		let mut revealed = None;
@@ -452,6 +803,16 @@ impl GameState {
 					return Err(GameStateMutationError::MustTakeNightAction);
 				}
 
+				let revealable_villagers = self
+					.villagers
+					.iter()
+					.filter(|villager| match villager {
+						Villager::Hidden(hidden_villager) => !hidden_villager.cant_reveal(),
+						Villager::Active(_) | Villager::Confirmed(_) => false,
+					})
+					.count();
+				let blocked_reveals = self.rule_set.witch_blocked_reveals();
+
 				let target_villager = &mut self.villagers[result.index.0];
 				match target_villager {
 					Villager::Active(_) | Villager::Confirmed(_) => {
@@ -478,11 +839,26 @@ impl GameState {
 									return Err(GameStateMutationError::RevealNoActionNorTestimony);
 								}
 
+								validate_revealed_testimony(
+									&instance,
+									&result.index,
+									total_villagers,
+								)?;
+
 								revealed = Some(result.index.clone());
 								self.villagers[result.index.0] =
 									Villager::Active(ActiveVillager::new(instance))
 							}
-							None => hidden_villager.set_cant_reveal(),
+							None => {
+								// the witch only blocks the last few reveals
+								if revealable_villagers > blocked_reveals {
+									return Err(GameStateMutationError::RevealCannotBeBlocked(
+										blocked_reveals,
+									));
+								}
+
+								hidden_villager.set_cant_reveal()
+							}
 						}
 					}
 				}
@@ -505,7 +881,7 @@ impl GameState {
 									kill_data.true_identity,
 									kill_data.corrupted,
 								);
-								match confirmed_villager.execution_result() {
+								match self.rule_set.execution_result(&confirmed_villager) {
 									ExecutionResult::EvilKilled => {}
 									ExecutionResult::SelfDestructKilled => {
 										return Ok(GameStateMutationResult::Loss);
@@ -557,12 +933,18 @@ impl GameState {
 											GameStateMutationError::RevealNoActionNorTestimony,
 										);
 									}
+
+									validate_revealed_testimony(
+										&new_instance,
+										&attempt.target,
+										total_villagers,
+									)?;
 									let confirmed_villager = ConfirmedVillager::new(
 										new_instance,
 										kill_data.inner.true_identity,
 										kill_data.inner.corrupted,
 									);
-									match confirmed_villager.execution_result() {
+									match self.rule_set.execution_result(&confirmed_villager) {
 										ExecutionResult::EvilKilled => {}
 										ExecutionResult::SelfDestructKilled => {
 											return Ok(GameStateMutationResult::Loss);
@@ -619,6 +1001,15 @@ impl GameState {
 					return Err(GameStateMutationError::AbilityNotAvailable);
 				}
 
+				if let Some(testimony) = &result.testimony {
+					validate_testimony(
+						instance.archetype(),
+						&result.source,
+						testimony,
+						total_villagers,
+					)?;
+				}
+
 				let mut slayer_kill = None;
 				match result.testimony {
 					Some(testimony) => {
@@ -686,7 +1077,7 @@ impl GameState {
 									match &slayer_kill.result {
 										KillResult::Unrevealed(kill_data) => {
 											let new_instance = &kill_data.instance;
-											if valid_draw(&self.deck, new_instance.archetype()) {
+											if !valid_draw(&self.deck, new_instance.archetype()) {
 												return Err(GameStateMutationError::InvalidReveal);
 											}
 
@@ -698,6 +1089,12 @@ impl GameState {
 												return Err(GameStateMutationError::RevealNoActionNorTestimony);
 											}
 
+											validate_revealed_testimony(
+												new_instance,
+												&slayer_kill.target,
+												total_villagers,
+											)?;
+
 											let confirmed_villager = ConfirmedVillager::new(
 												new_instance.clone(),
 												kill_data.inner.true_identity.clone(),
@@ -728,7 +1125,6 @@ impl GameState {
 							}
 						}
 
-						// TODO: Validate more testimonies if it makes sense to do so
 						match &mut self.villagers[result.source.0] {
 							Villager::Active(active_villager) => {
 								active_villager.instance_mut().set_testimony(testimony)
@@ -810,14 +1206,17 @@ impl GameState {
 	}
 }
 
+/// Starts a game with every villager hidden. The deck is checked with [`validate_deck`] first
 pub fn new_game(
 	deck: Vec<VillagerArchetype>,
 	draw_stats: DrawStats,
 	total_evils: usize,
 	night_effects_active: bool,
-) -> GameState {
+) -> Result<GameState, DeckError> {
+	validate_deck(&deck, &draw_stats, total_evils)?;
+
 	let total_villagers = draw_stats.total_villagers();
-	GameState::new(
+	Ok(GameState::new(
 		if night_effects_active { Some(1) } else { None },
 		draw_stats,
 		deck,
@@ -828,7 +1227,7 @@ pub fn new_game(
 		10,
 		total_evils,
 	)
-	.expect("logic error in new_game creation")
+	.expect("logic error in new_game creation"))
 }
 
 fn valid_draw(deck: &Vec<VillagerArchetype>, archetype: &VillagerArchetype) -> bool {
@@ -841,3 +1240,279 @@ fn valid_draw(deck: &Vec<VillagerArchetype>, archetype: &VillagerArchetype) -> b
 
 	false
 }
+
+#[test]
+fn test_undo_redo() {
+	let mut state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(2, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	let initial_villagers = format!("{:?}", state.villagers());
+	state
+		.mutate(Action::TryReveal(RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(
+					testimony::ConfessorClaim::Good,
+				))),
+			)),
+		)))
+		.unwrap();
+	let revealed_villagers = format!("{:?}", state.villagers());
+	assert_eq!(
+		GameStateMutationResult::Continue,
+		state
+			.mutate(Action::TryExecute(KillAttempt::new(
+				VillagerIndex(0),
+				Some(KillResult::Revealed(KillData::new(None, false).unwrap())),
+			)))
+			.unwrap()
+	);
+	assert!(state.hitpoints() < 10);
+
+	// a failed action leaves everything as it was
+	let killed_villagers = format!("{:?}", state.villagers());
+	assert!(
+		state
+			.mutate(Action::TryReveal(RevealResult::new(
+				VillagerIndex(1),
+				Some(VillagerInstance::new(
+					VillagerArchetype::Demon(Demon::Pooka),
+					None
+				)),
+			)))
+			.is_err()
+	);
+	assert_eq!(killed_villagers, format!("{:?}", state.villagers()));
+	assert_eq!(2, state.history().count());
+
+	assert!(matches!(state.undo(), Some(Action::TryExecute(_))));
+	assert_eq!(10, state.hitpoints());
+	assert_eq!(revealed_villagers, format!("{:?}", state.villagers()));
+	assert!(matches!(state.undo(), Some(Action::TryReveal(_))));
+	assert!(state.undo().is_none());
+	assert_eq!(initial_villagers, format!("{:?}", state.villagers()));
+	assert!(state.reveal_order().is_empty());
+
+	assert_eq!(Some(GameStateMutationResult::Continue), state.redo());
+	assert_eq!(revealed_villagers, format!("{:?}", state.villagers()));
+	assert_eq!(vec![VillagerIndex(0)], *state.reveal_order());
+
+	// a new action replaces whatever was undone
+	state
+		.mutate(Action::TryReveal(RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Knight),
+				Some(Expression::Leaf(Testimony::Invincible(VillagerIndex(1)))),
+			)),
+		)))
+		.unwrap();
+	assert!(state.redo().is_none());
+	assert_eq!(2, state.history().count());
+}
+
+#[test]
+fn test_testimony_validation() {
+	let mut state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(3, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+	let reveal = |index: usize, good_villager: GoodVillager, testimony: Expression<Testimony>| {
+		Action::TryReveal(RevealResult::new(
+			VillagerIndex(index),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(good_villager),
+				Some(testimony),
+			)),
+		))
+	};
+
+	assert!(matches!(
+		state.mutate(reveal(
+			0,
+			GoodVillager::Confessor,
+			Testimony::hunter(&VillagerIndex(0), 1, 4)
+		)),
+		Err(GameStateMutationError::TestimonyDoesNotMatchArchetype { .. })
+	));
+	assert!(matches!(
+		state.mutate(reveal(
+			0,
+			GoodVillager::Confessor,
+			Expression::Leaf(Testimony::Good(VillagerIndex(4)))
+		)),
+		Err(GameStateMutationError::Testimony(
+			TestimonyError::IndexOutOfRange {
+				index: VillagerIndex(4),
+				total_villagers: 4
+			}
+		))
+	));
+
+	// a Lover only has 2 neighbours to be evil
+	let three_evils = Expression::And(
+		Box::new(Expression::Leaf(Testimony::Evil(VillagerIndex(0)))),
+		Box::new(Expression::And(
+			Box::new(Expression::Leaf(Testimony::Evil(VillagerIndex(2)))),
+			Box::new(Expression::Leaf(Testimony::Evil(VillagerIndex(3)))),
+		)),
+	);
+	assert!(matches!(
+		state.mutate(reveal(1, GoodVillager::Lover, three_evils)),
+		Err(GameStateMutationError::TestimonyDoesNotMatchArchetype { .. })
+	));
+	assert!(matches!(
+		state.mutate(reveal(
+			1,
+			GoodVillager::Lover,
+			Testimony::lover(&VillagerIndex(2), 1, 4)
+		)),
+		Err(GameStateMutationError::TestimonyDoesNotMatchArchetype { .. })
+	));
+	assert_eq!(0, state.history().count());
+
+	state
+		.mutate(reveal(
+			1,
+			GoodVillager::Lover,
+			Testimony::lover(&VillagerIndex(1), 1, 4),
+		))
+		.unwrap();
+	state
+		.mutate(reveal(
+			2,
+			GoodVillager::Hunter,
+			Testimony::hunter(&VillagerIndex(2), 2, 4),
+		))
+		.unwrap();
+	state
+		.mutate(reveal(
+			0,
+			GoodVillager::Confessor,
+			Expression::Leaf(Testimony::Confess(testimony::ConfessorClaim::Dizzy)),
+		))
+		.unwrap();
+}
+
+#[test]
+fn test_slayer_unrevealed_kill() {
+	let mut state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Slayer),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(3, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+	state
+		.mutate(Action::TryReveal(RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Slayer),
+				None,
+			)),
+		)))
+		.unwrap();
+
+	let slay = |archetype: GoodVillager| {
+		Action::Ability(AbilityResult::new(
+			VillagerIndex(0),
+			Some(Expression::Leaf(Testimony::SlayAttempt(
+				testimony::SlayResult::new(VillagerIndex(2), true),
+			))),
+			Some(SlayerKill::new(
+				VillagerIndex(2),
+				KillResult::Unrevealed(UnrevealedKillData::new(
+					VillagerInstance::new(
+						VillagerArchetype::GoodVillager(archetype),
+						Some(Expression::Leaf(Testimony::Invincible(VillagerIndex(2)))),
+					),
+					KillData::new(Some(VillagerArchetype::Minion(Minion::Minion)), false).unwrap(),
+				)),
+			)),
+		))
+	};
+
+	// the slain villager has to show something from the deck
+	assert!(matches!(
+		state.mutate(slay(GoodVillager::Hunter)),
+		Err(GameStateMutationError::InvalidReveal)
+	));
+	assert_eq!(
+		GameStateMutationResult::Win,
+		state.mutate(slay(GoodVillager::Knight)).unwrap()
+	);
+}
+
+#[test]
+fn test_witch_blocked_reveals() {
+	let game = |rule_set: RuleSet| {
+		let mut state = new_game(
+			vec![
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				VillagerArchetype::GoodVillager(GoodVillager::Knight),
+				VillagerArchetype::GoodVillager(GoodVillager::Lover),
+				VillagerArchetype::Minion(Minion::Witch),
+			],
+			DrawStats::new(3, 0, 1, 0),
+			1,
+			false,
+		)
+		.expect("Invalid deck")
+		.with_rule_set(rule_set);
+		state
+			.mutate(Action::TryReveal(RevealResult::new(
+				VillagerIndex(0),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+					Some(Expression::Leaf(Testimony::Confess(
+						testimony::ConfessorClaim::Good,
+					))),
+				)),
+			)))
+			.unwrap();
+		state
+			.mutate(Action::TryReveal(RevealResult::new(
+				VillagerIndex(1),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Knight),
+					Some(Expression::Leaf(Testimony::Invincible(VillagerIndex(1)))),
+				)),
+			)))
+			.unwrap();
+		state
+	};
+
+	// two villagers are left to reveal
+	let blocked = Action::TryReveal(RevealResult::new(VillagerIndex(2), None));
+	assert!(matches!(
+		game(RuleSet::current()).mutate(blocked.clone()),
+		Err(GameStateMutationError::RevealCannotBeBlocked(1))
+	));
+	assert_eq!(
+		GameStateMutationResult::Continue,
+		game(RuleSet::early_access()).mutate(blocked).unwrap()
+	);
+}
@@ -8,13 +8,15 @@ use serde::{Deserialize, Serialize};
 extern crate enum_display_derive;
 
 pub mod affect;
+pub mod deck;
 pub mod game_state;
+pub mod notation;
+pub mod replay;
+pub mod roles;
+pub mod rule_set;
 pub mod testimony;
 pub mod villager;
 
-pub const VILLAGERS_MIN: usize = 7;
-pub const VILLAGERS_MAX: usize = 9;
-
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub enum Expression<Type> {
 	Leaf(Type),
@@ -37,9 +39,10 @@ where
 			Self::MajorOr(expressions) => {
 				let mut first = true;
 				for expression in expressions {
-					if !first {
-						write!(f, " || ")?;
+					if first {
 						first = false;
+					} else {
+						write!(f, " || ")?;
 					}
 
 					write!(f, "({})", expression)?
@@ -75,4 +78,21 @@ impl<Type> Expression<Type> {
 
 		expr
 	}
+
+	/// Every [`Expression::Leaf`] in the tree, left to right
+	pub fn leaves(&self) -> Vec<&Type> {
+		match self {
+			Self::Leaf(item) => vec![item],
+			Self::Not(item) => item.leaves(),
+			Self::And(lhs, rhs) | Self::Or(lhs, rhs) => {
+				let mut leaves = lhs.leaves();
+				leaves.extend(rhs.leaves());
+				leaves
+			}
+			Self::MajorOr(expressions) => expressions
+				.iter()
+				.flat_map(|expression| expression.leaves())
+				.collect(),
+		}
+	}
 }
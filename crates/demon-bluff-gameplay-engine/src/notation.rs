@@ -0,0 +1,871 @@
+use std::{fmt::Display, num::NonZeroUsize, str::FromStr};
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::{
+	Expression,
+	deck::DeckError,
+	game_state::{
+		AbilityResult, Action, DrawStats, GameState, GameStateMutationError,
+		GameStateMutationResult, KillAttempt, KillData, KillDataConstructionError, KillResult,
+		RevealResult, SlayerKill, UnrevealedKillData, new_game,
+	},
+	rule_set::RuleSet,
+	testimony::{
+		AffectedClaim, ArchitectClaim, BakerClaim, BishopClaim, BishopEvil, DreamerClaim,
+		DruidClaim, EvilPairsClaim, FortuneTellerClaim, RoleClaim, ScoutClaim, SlayResult,
+		Testimony,
+	},
+	villager::{GoodVillager, Outcast, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+
+#[derive(Error, Debug)]
+pub enum NotationError {
+	#[error("Unrecognized testimony: {0}")]
+	UnknownTestimony(String),
+	#[error("Unrecognized villager archetype: {0}")]
+	UnknownArchetype(String),
+	#[error("Invalid villager index: {0}")]
+	InvalidVillagerIndex(String),
+	#[error("Invalid number: {0}")]
+	InvalidNumber(String),
+	#[error("Unbalanced parentheses: {0}")]
+	UnbalancedParentheses(String),
+	#[error("Expected a single kind of operator between parenthesized expressions: {0}")]
+	InvalidOperator(String),
+	#[error("Unrecognized action: {0}")]
+	UnknownAction(String),
+	#[error("Unrecognized draw stats: {0}")]
+	InvalidDrawStats(String),
+	#[error("Unrecognized rule set: {0}")]
+	UnknownRuleSet(String),
+	#[error("Missing transcript header: {0}")]
+	MissingHeader(&'static str),
+	#[error("Invalid {0} header: {1}")]
+	InvalidHeader(&'static str, String),
+	#[error("Invalid kill data: {0}")]
+	InvalidKillData(#[from] KillDataConstructionError),
+	#[error("Line {line}: {error}")]
+	Line {
+		line: usize,
+		error: Box<NotationError>,
+	},
+}
+
+#[derive(Error, Debug)]
+pub enum TranscriptError {
+	#[error("Invalid deck: {0}")]
+	InvalidDeck(#[from] DeckError),
+	#[error("Invalid action: {0}")]
+	Mutation(#[from] GameStateMutationError),
+}
+
+/// A recorded game. Written as a header followed by the [`Display`] of one [`Action`] per line:
+///
+/// ```text
+/// Deck: Lover, Gemcrafter, Confessor, Hunter, Minion
+/// Draw: 4 villagers, 0 outcasts, 1 minions, 0 demons
+/// Evils: 1
+/// Night effects: false
+/// Rules: card-text
+/// Reveal #1 Lover {(#2 is good) && (#5 is evil)}
+/// Reveal #2 Confessor {I confess to being Dizzy}
+/// Kill #2 => Minion
+/// ```
+///
+/// The `Rules` header names one of the [`RuleSet::profiles`] and can be left out for the current rules.
+/// Testimonies are their [`Display`] in braces. A kill result is the true identity, or `claimed` if the villager was what it appeared to be,
+/// followed by `, corrupted` if it was and `, unrevealed <archetype> {testimony}` if the target hadn't been revealed yet.
+/// Abilities are `Ability #N {testimony} slays #M => <kill result>` with either part optional and Lilis kills are `Lilis kills #N` or `Lilis kills nobody`.
+/// Blank lines and lines starting with `//` are ignored
+#[derive(Debug, Clone)]
+pub struct Transcript {
+	pub deck: Vec<VillagerArchetype>,
+	pub draw_stats: DrawStats,
+	pub total_evils: usize,
+	pub night_effects_active: bool,
+	pub rule_set: RuleSet,
+	pub actions: Vec<Action>,
+}
+
+enum TranscriptLine {
+	Deck(Vec<VillagerArchetype>),
+	Draw(DrawStats),
+	Evils(usize),
+	NightEffects(bool),
+	Rules(RuleSet),
+	Action(Action),
+}
+
+impl Transcript {
+	pub fn new_game(&self) -> Result<GameState, DeckError> {
+		new_game(
+			self.deck.clone(),
+			self.draw_stats.clone(),
+			self.total_evils,
+			self.night_effects_active,
+		)
+		.map(|state| state.with_rule_set(self.rule_set.clone()))
+	}
+
+	/// Plays every action from a new game, stopping early if the game ends
+	pub fn replay(&self) -> Result<(GameState, GameStateMutationResult), TranscriptError> {
+		let mut state = self.new_game()?;
+		let mut result = GameStateMutationResult::Continue;
+		for action in &self.actions {
+			result = state.mutate(action.clone())?;
+			if result != GameStateMutationResult::Continue {
+				break;
+			}
+		}
+
+		Ok((state, result))
+	}
+}
+
+impl Display for Transcript {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Deck: ")?;
+		let mut first = true;
+		for archetype in &self.deck {
+			if first {
+				first = false;
+			} else {
+				write!(f, ", ")?;
+			}
+
+			write!(f, "{}", archetype)?;
+		}
+
+		writeln!(f)?;
+		writeln!(f, "Draw: {}", self.draw_stats)?;
+		writeln!(f, "Evils: {}", self.total_evils)?;
+		writeln!(f, "Night effects: {}", self.night_effects_active)?;
+		if self.rule_set != RuleSet::current() {
+			writeln!(f, "Rules: {}", self.rule_set)?;
+		}
+
+		for action in &self.actions {
+			writeln!(f, "{}", action)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl FromStr for Transcript {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut deck = None;
+		let mut draw_stats = None;
+		let mut total_evils = None;
+		let mut night_effects_active = None;
+		let mut rule_set = RuleSet::current();
+		let mut actions = Vec::new();
+		for (line_index, line) in s.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with("//") {
+				continue;
+			}
+
+			match parse_transcript_line(line).map_err(|error| NotationError::Line {
+				line: line_index + 1,
+				error: Box::new(error),
+			})? {
+				TranscriptLine::Deck(archetypes) => deck = Some(archetypes),
+				TranscriptLine::Draw(stats) => draw_stats = Some(stats),
+				TranscriptLine::Evils(evils) => total_evils = Some(evils),
+				TranscriptLine::NightEffects(active) => night_effects_active = Some(active),
+				TranscriptLine::Rules(rules) => rule_set = rules,
+				TranscriptLine::Action(action) => actions.push(action),
+			}
+		}
+
+		Ok(Self {
+			deck: deck.ok_or(NotationError::MissingHeader("Deck"))?,
+			draw_stats: draw_stats.ok_or(NotationError::MissingHeader("Draw"))?,
+			total_evils: total_evils.ok_or(NotationError::MissingHeader("Evils"))?,
+			night_effects_active: night_effects_active
+				.ok_or(NotationError::MissingHeader("Night effects"))?,
+			rule_set,
+			actions,
+		})
+	}
+}
+
+/// Reads back the [`Display`] of an action, see [`Transcript`]
+impl FromStr for Action {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse_action(s.trim())
+	}
+}
+
+impl FromStr for VillagerIndex {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.strip_prefix('#').map(str::parse::<usize>) {
+			Some(Ok(number)) => VillagerIndex::try_number(number)
+				.map_err(|_| NotationError::InvalidVillagerIndex(s.to_string())),
+			_ => Err(NotationError::InvalidVillagerIndex(s.to_string())),
+		}
+	}
+}
+
+impl FromStr for VillagerArchetype {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		VillagerArchetype::iter()
+			.find(|archetype| archetype.to_string() == s)
+			.ok_or_else(|| NotationError::UnknownArchetype(s.to_string()))
+	}
+}
+
+impl FromStr for DrawStats {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let counts: Vec<&str> = s.split(", ").collect();
+		let [villagers, outcasts, minions, demons] = counts.as_slice() else {
+			return Err(NotationError::InvalidDrawStats(s.to_string()));
+		};
+
+		let count = |text: &str, suffix: &str| match text.strip_suffix(suffix) {
+			Some(number) => parse_number(number),
+			None => Err(NotationError::InvalidDrawStats(s.to_string())),
+		};
+
+		Ok(DrawStats::new(
+			count(villagers, " villagers")?,
+			count(outcasts, " outcasts")?,
+			count(minions, " minions")?,
+			count(demons, " demons")?,
+		))
+	}
+}
+
+/// Reads back the [`Display`] of an expression.
+/// A [`Expression::MajorOr`] of fewer than three expressions is written the same as the [`Expression::Or`] or single expression it is equivalent to and reads back as such
+impl<Type> FromStr for Expression<Type>
+where
+	Type: FromStr<Err = NotationError>,
+{
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		if let Some(negated) = s.strip_prefix('!') {
+			if negated.starts_with('(') && closing_parenthesis(negated)? == negated.len() - 1 {
+				return Ok(Expression::Not(Box::new(
+					negated[1..negated.len() - 1].parse()?,
+				)));
+			}
+
+			return Err(NotationError::UnbalancedParentheses(s.to_string()));
+		}
+
+		if !s.starts_with('(') {
+			return Ok(Expression::Leaf(s.parse()?));
+		}
+
+		let mut operands = Vec::new();
+		let mut conjunction = None;
+		let mut remaining = s;
+		loop {
+			let closing_index = closing_parenthesis(remaining)?;
+			operands.push(remaining[1..closing_index].parse()?);
+			remaining = &remaining[closing_index + 1..];
+			if remaining.is_empty() {
+				break;
+			}
+
+			let (is_and, rhs) = if let Some(rhs) = remaining.strip_prefix(" && ") {
+				(true, rhs)
+			} else if let Some(rhs) = remaining.strip_prefix(" || ") {
+				(false, rhs)
+			} else {
+				return Err(NotationError::InvalidOperator(s.to_string()));
+			};
+
+			if conjunction.is_some_and(|previous| previous != is_and) || !rhs.starts_with('(') {
+				return Err(NotationError::InvalidOperator(s.to_string()));
+			}
+
+			conjunction = Some(is_and);
+			remaining = rhs;
+		}
+
+		match (conjunction, operands.len()) {
+			(None, 1) => Ok(operands.pop().unwrap()),
+			(Some(true), 2) => {
+				let rhs = operands.pop().unwrap();
+				let lhs = operands.pop().unwrap();
+				Ok(Expression::And(Box::new(lhs), Box::new(rhs)))
+			}
+			(Some(false), 2) => {
+				let rhs = operands.pop().unwrap();
+				let lhs = operands.pop().unwrap();
+				Ok(Expression::Or(Box::new(lhs), Box::new(rhs)))
+			}
+			(Some(false), _) => Ok(Expression::MajorOr(operands)),
+			_ => Err(NotationError::InvalidOperator(s.to_string())),
+		}
+	}
+}
+
+/// Reads back the [`Display`] of a testimony
+impl FromStr for Testimony {
+	type Err = NotationError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let unknown = || NotationError::UnknownTestimony(s.to_string());
+		match s {
+			"I was the OG Baker" => return Ok(Testimony::Baker(BakerClaim::new(None))),
+			"No one was affected" => return Ok(Testimony::Affected(None)),
+			"There are no corrupted characters" => return Ok(Testimony::Bard(None)),
+			"Left side is more evil" => return Ok(Testimony::Architect(ArchitectClaim::Left)),
+			"Right side is more evil" => return Ok(Testimony::Architect(ArchitectClaim::Right)),
+			"Both sides are equally evil" => {
+				return Ok(Testimony::Architect(ArchitectClaim::Equal));
+			}
+			_ => {}
+		}
+
+		if let Some(good_villager) = s.strip_prefix("I was a ") {
+			return Ok(Testimony::Baker(BakerClaim::new(Some(
+				GoodVillager::iter()
+					.find(|candidate| candidate.to_string() == good_villager)
+					.ok_or_else(|| NotationError::UnknownArchetype(good_villager.to_string()))?,
+			))));
+		}
+
+		if let Some(distance) = s
+			.strip_prefix("I am ")
+			.and_then(|rest| rest.strip_suffix(" away from the closest corrupted character"))
+		{
+			return Ok(Testimony::Bard(Some(
+				NonZeroUsize::new(parse_number(distance)?)
+					.ok_or_else(|| NotationError::InvalidNumber(distance.to_string()))?,
+			)));
+		}
+
+		if let Some(target) = s.strip_prefix("I killed ") {
+			return Ok(Testimony::SlayAttempt(SlayResult::new(
+				target.parse()?,
+				true,
+			)));
+		}
+
+		if let Some(target) = s.strip_prefix("I couldn't kill ") {
+			return Ok(Testimony::SlayAttempt(SlayResult::new(
+				target.parse()?,
+				false,
+			)));
+		}
+
+		if let Some(claim) = s.strip_prefix("I confess to being ") {
+			return Ok(Testimony::Confess(claim.parse().map_err(|_| unknown())?));
+		}
+
+		if let Some(direction) = s.strip_prefix("Closest evil is ") {
+			return Ok(Testimony::Enlightened(
+				direction.parse().map_err(|_| unknown())?,
+			));
+		}
+
+		if let Some(pairs) = s
+			.strip_prefix("There are ")
+			.and_then(|rest| rest.strip_suffix(" pairs of adjacent evils"))
+		{
+			return Ok(Testimony::Knitter(EvilPairsClaim::new(parse_number(
+				pairs,
+			)?)));
+		}
+
+		if let Some(claim) = s.strip_prefix("Is ") {
+			let (targets, evil) = claim.split_once(" evil? ").ok_or_else(unknown)?;
+			let (first, second) = targets.split_once(" or ").ok_or_else(unknown)?;
+			let evil = match evil {
+				"TRUE" => true,
+				"FALSE" => false,
+				_ => return Err(unknown()),
+			};
+
+			return Ok(Testimony::FortuneTeller(FortuneTellerClaim::new(
+				&[first.parse()?, second.parse()?],
+				evil,
+			)));
+		}
+
+		if let Some(claim) = s.strip_prefix("Among ") {
+			let (targets, found) = claim.split_once(" there ").ok_or_else(unknown)?;
+			let targets: [VillagerIndex; 3] = targets
+				.split(", ")
+				.map(VillagerIndex::from_str)
+				.collect::<Result<Vec<VillagerIndex>, NotationError>>()?
+				.try_into()
+				.map_err(|_| unknown())?;
+
+			if found == "are NO outcasts" {
+				return Ok(Testimony::Druid(DruidClaim::new(&targets, None)));
+			}
+
+			if let Some(outcast) = found.strip_prefix("is a ") {
+				return Ok(Testimony::Druid(DruidClaim::new(
+					&targets,
+					Some(
+						Outcast::iter()
+							.find(|candidate| candidate.to_string() == outcast)
+							.ok_or_else(|| NotationError::UnknownArchetype(outcast.to_string()))?,
+					),
+				)));
+			}
+
+			let found = found.strip_prefix("are").ok_or_else(unknown)?;
+			let mut good_villager = false;
+			let mut outcast = false;
+			let mut evil = None;
+			for kind in found.split_whitespace() {
+				match kind {
+					"GoodVillager" => good_villager = true,
+					"Outcast" => outcast = true,
+					"Minion" => evil = Some(BishopEvil::Minion),
+					"Demon" => evil = Some(BishopEvil::Demon),
+					_ => return Err(unknown()),
+				}
+			}
+
+			return Ok(Testimony::Bishop(BishopClaim::new(
+				&targets,
+				good_villager,
+				outcast,
+				evil,
+			)));
+		}
+
+		if let Some(claim) = s.strip_prefix("I dreamt ") {
+			let (target, role) = claim.split_once(" is a ").ok_or_else(unknown)?;
+			return Ok(Testimony::Dreamer(DreamerClaim::new(
+				target.parse()?,
+				match role {
+					"cabbage" => None,
+					role => Some(role.parse()?),
+				},
+			)));
+		}
+
+		if let Some(claim) = s.strip_suffix(" away from the nearest evil") {
+			let (evil_role, distance) = claim.rsplit_once(" is ").ok_or_else(unknown)?;
+			return Ok(Testimony::Scout(ScoutClaim::new(
+				evil_role.parse()?,
+				parse_number(distance)?,
+			)));
+		}
+
+		if let Some(cured) = s.strip_suffix(" was cured of corruption") {
+			return Ok(Testimony::Cured(parse_number(cured)?));
+		}
+
+		let (target, claim) = s.split_once(' ').ok_or_else(unknown)?;
+		let target: VillagerIndex = target.parse().map_err(|_| unknown())?;
+		Ok(match claim {
+			"is good" => Testimony::Good(target),
+			"is evil" => Testimony::Evil(target),
+			"is corrupt" => Testimony::Corrupt(target),
+			"is lying" => Testimony::Lying(target),
+			"is invincible" => Testimony::Invincible(target),
+			"looks evil but isn't" => Testimony::FakeEvil(target),
+			"will self destruct" => Testimony::SelfDestruct(target),
+			claim => {
+				if let Some(archetype) = claim.strip_prefix("is a ") {
+					Testimony::Role(RoleClaim::new(target, archetype.parse()?))
+				} else if let Some(affect_type) = claim.strip_prefix("was ") {
+					Testimony::Affected(Some(AffectedClaim::new(
+						target,
+						affect_type.parse().map_err(|_| unknown())?,
+					)))
+				} else {
+					return Err(unknown());
+				}
+			}
+		})
+	}
+}
+
+fn parse_number(text: &str) -> Result<usize, NotationError> {
+	text.parse()
+		.map_err(|_| NotationError::InvalidNumber(text.to_string()))
+}
+
+/// The index of the parenthesis closing the one `text` starts with
+fn closing_parenthesis(text: &str) -> Result<usize, NotationError> {
+	let mut depth = 0;
+	for (index, character) in text.char_indices() {
+		match character {
+			'(' => depth += 1,
+			')' => {
+				depth -= 1;
+				if depth == 0 {
+					return Ok(index);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	Err(NotationError::UnbalancedParentheses(text.to_string()))
+}
+
+fn parse_transcript_line(line: &str) -> Result<TranscriptLine, NotationError> {
+	if let Some(deck) = line.strip_prefix("Deck:") {
+		return Ok(TranscriptLine::Deck(
+			deck.split(',')
+				.map(str::trim)
+				.filter(|archetype| !archetype.is_empty())
+				.map(VillagerArchetype::from_str)
+				.collect::<Result<Vec<VillagerArchetype>, NotationError>>()?,
+		));
+	}
+
+	if let Some(draw_stats) = line.strip_prefix("Draw:") {
+		return Ok(TranscriptLine::Draw(draw_stats.trim().parse()?));
+	}
+
+	if let Some(evils) = line.strip_prefix("Evils:") {
+		return Ok(TranscriptLine::Evils(parse_number(evils.trim())?));
+	}
+
+	if let Some(night_effects) = line.strip_prefix("Night effects:") {
+		return match night_effects.trim() {
+			"true" => Ok(TranscriptLine::NightEffects(true)),
+			"false" => Ok(TranscriptLine::NightEffects(false)),
+			value => Err(NotationError::InvalidHeader(
+				"Night effects",
+				value.to_string(),
+			)),
+		};
+	}
+
+	if let Some(rules) = line.strip_prefix("Rules:") {
+		let rules = rules.trim();
+		return RuleSet::named(rules)
+			.map(TranscriptLine::Rules)
+			.ok_or_else(|| NotationError::UnknownRuleSet(rules.to_string()));
+	}
+
+	Ok(TranscriptLine::Action(parse_action(line)?))
+}
+
+fn parse_action(line: &str) -> Result<Action, NotationError> {
+	let unknown = || NotationError::UnknownAction(line.to_string());
+	if let Some(reveal) = line.strip_prefix("Reveal ") {
+		return Ok(Action::TryReveal(match reveal.split_once(' ') {
+			Some((index, instance)) => {
+				RevealResult::new(index.parse()?, Some(parse_instance(instance)?))
+			}
+			None => RevealResult::new(reveal.parse()?, None),
+		}));
+	}
+
+	if let Some(kill) = line.strip_prefix("Kill ") {
+		return Ok(Action::TryExecute(match kill.split_once(" => ") {
+			Some((target, result)) => {
+				KillAttempt::new(target.parse()?, Some(parse_kill_result(result)?))
+			}
+			None => KillAttempt::new(kill.parse()?, None),
+		}));
+	}
+
+	if let Some(ability) = line.strip_prefix("Ability ") {
+		let (source, mut remaining) = ability.split_once(' ').unwrap_or((ability, ""));
+		let mut testimony = None;
+		if let Some(braced) = remaining.strip_prefix('{') {
+			let (expression, rest) = braced.split_once('}').ok_or_else(unknown)?;
+			testimony = Some(expression.parse()?);
+			remaining = rest.trim_start();
+		}
+
+		let mut slayer_kill = None;
+		if let Some(kill) = remaining.strip_prefix("slays ") {
+			let (target, result) = kill.split_once(" => ").ok_or_else(unknown)?;
+			slayer_kill = Some(SlayerKill::new(target.parse()?, parse_kill_result(result)?));
+		} else if !remaining.is_empty() {
+			return Err(unknown());
+		}
+
+		return Ok(Action::Ability(AbilityResult::new(
+			source.parse()?,
+			testimony,
+			slayer_kill,
+		)));
+	}
+
+	if let Some(target) = line.strip_prefix("Lilis kills ") {
+		return Ok(Action::LilisNightKill(match target {
+			"nobody" => None,
+			target => Some(target.parse()?),
+		}));
+	}
+
+	Err(unknown())
+}
+
+fn parse_instance(text: &str) -> Result<VillagerInstance, NotationError> {
+	match text.split_once(" {") {
+		Some((archetype, testimony)) => Ok(VillagerInstance::new(
+			archetype.parse()?,
+			Some(
+				testimony
+					.strip_suffix('}')
+					.ok_or_else(|| NotationError::UnknownAction(text.to_string()))?
+					.parse()?,
+			),
+		)),
+		None => Ok(VillagerInstance::new(text.parse()?, None)),
+	}
+}
+
+fn parse_kill_result(text: &str) -> Result<KillResult, NotationError> {
+	let (kill_data, instance) = match text.split_once(", unrevealed ") {
+		Some((kill_data, instance)) => (kill_data, Some(parse_instance(instance)?)),
+		None => (text, None),
+	};
+
+	let (true_identity, corrupted) = match kill_data.strip_suffix(", corrupted") {
+		Some(true_identity) => (true_identity, true),
+		None => (kill_data, false),
+	};
+
+	let kill_data = KillData::new(
+		match true_identity {
+			"claimed" => None,
+			true_identity => Some(true_identity.parse()?),
+		},
+		corrupted,
+	)?;
+
+	Ok(match instance {
+		Some(instance) => KillResult::Unrevealed(UnrevealedKillData::new(instance, kill_data)),
+		None => KillResult::Revealed(kill_data),
+	})
+}
+
+#[test]
+fn test_testimony_round_trip() {
+	use crate::{
+		testimony::{AffectType, ConfessorClaim, Direction},
+		villager::{Demon, Minion},
+	};
+
+	let testimonies = vec![
+		Testimony::Good(VillagerIndex(0)),
+		Testimony::Evil(VillagerIndex(8)),
+		Testimony::Corrupt(VillagerIndex(2)),
+		Testimony::Lying(VillagerIndex(3)),
+		Testimony::Cured(2),
+		Testimony::Baker(BakerClaim::new(None)),
+		Testimony::Baker(BakerClaim::new(Some(GoodVillager::FortuneTeller))),
+		Testimony::Role(RoleClaim::new(
+			VillagerIndex(4),
+			VillagerArchetype::Minion(Minion::Twinion),
+		)),
+		Testimony::Invincible(VillagerIndex(1)),
+		Testimony::Affected(None),
+		Testimony::Affected(Some(AffectedClaim::new(
+			VillagerIndex(5),
+			AffectType::CorruptedByEvil,
+		))),
+		Testimony::FakeEvil(VillagerIndex(6)),
+		Testimony::SelfDestruct(VillagerIndex(7)),
+		Testimony::SlayAttempt(SlayResult::new(VillagerIndex(1), true)),
+		Testimony::SlayAttempt(SlayResult::new(VillagerIndex(2), false)),
+		Testimony::Confess(ConfessorClaim::Dizzy),
+		Testimony::Scout(ScoutClaim::new(VillagerArchetype::Demon(Demon::Pooka), 3)),
+		Testimony::Enlightened(Direction::CounterClockwise),
+		Testimony::Knitter(EvilPairsClaim::new(1)),
+		Testimony::Bard(None),
+		Testimony::Bard(NonZeroUsize::new(2)),
+		Testimony::FortuneTeller(FortuneTellerClaim::new(
+			&[VillagerIndex(0), VillagerIndex(3)],
+			true,
+		)),
+		Testimony::Druid(DruidClaim::new(
+			&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)],
+			None,
+		)),
+		Testimony::Druid(DruidClaim::new(
+			&[VillagerIndex(3), VillagerIndex(4), VillagerIndex(5)],
+			Some(Outcast::PlagueDoctor),
+		)),
+		Testimony::Architect(ArchitectClaim::Equal),
+		Testimony::Bishop(BishopClaim::new(
+			&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)],
+			true,
+			false,
+			Some(BishopEvil::Demon),
+		)),
+		Testimony::Bishop(BishopClaim::new(
+			&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)],
+			false,
+			false,
+			None,
+		)),
+		Testimony::Dreamer(DreamerClaim::new(VillagerIndex(2), None)),
+		Testimony::Dreamer(DreamerClaim::new(
+			VillagerIndex(2),
+			Some(VillagerArchetype::GoodVillager(GoodVillager::Knight)),
+		)),
+	];
+
+	for testimony in testimonies {
+		let text = testimony.to_string();
+		assert_eq!(testimony, text.parse::<Testimony>().unwrap(), "{}", text);
+	}
+}
+
+#[test]
+fn test_expression_round_trip() {
+	let expressions = vec![
+		Testimony::hunter(&VillagerIndex(1), 2, 5),
+		Testimony::jester(&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)], 1),
+		Testimony::empress(&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)]),
+		Expression::MajorOr(vec![
+			Expression::Leaf(Testimony::Good(VillagerIndex(0))),
+			Expression::Not(Box::new(Expression::Leaf(Testimony::Good(VillagerIndex(
+				1,
+			))))),
+			Testimony::lover(&VillagerIndex(3), 1, 7),
+		]),
+	];
+
+	for expression in expressions {
+		let text = expression.to_string();
+		assert_eq!(
+			expression,
+			text.parse::<Expression<Testimony>>().unwrap(),
+			"{}",
+			text
+		);
+	}
+
+	assert!(matches!(
+		"(#1 is good) && (#2 is good) || (#3 is good)".parse::<Expression<Testimony>>(),
+		Err(NotationError::InvalidOperator(_))
+	));
+	assert!(matches!(
+		"(#1 is good".parse::<Expression<Testimony>>(),
+		Err(NotationError::UnbalancedParentheses(_))
+	));
+}
+
+#[test]
+fn test_transcript_round_trip() {
+	use crate::testimony::TestimonyError;
+
+	let text = "\
+// game_0001 from the simple strategy tests
+Deck: Lover, Gemcrafter, Confessor, Hunter, Minion
+Draw: 4 villagers, 0 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: false
+Reveal #1 Lover {((#2 is good) && (#5 is evil)) || ((#2 is evil) && (#5 is good))}
+Reveal #2 Confessor {I confess to being Dizzy}
+Kill #2 => Minion
+";
+
+	let transcript: Transcript = text.parse().unwrap();
+	assert_eq!(3, transcript.actions.len());
+	assert_eq!(
+		text.lines().skip(1).collect::<Vec<&str>>().join("\n") + "\n",
+		transcript.to_string()
+	);
+
+	let (_, result) = transcript.replay().unwrap();
+	assert_eq!(GameStateMutationResult::Win, result);
+
+	let other_actions = "\
+Deck: Slayer, Minion
+Draw: 1 villagers, 0 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: true
+Reveal #1
+Kill #2 => claimed, corrupted, unrevealed Slayer
+Ability #1 {I killed #2} slays #2 => Minion
+Ability #1
+Lilis kills nobody
+Lilis kills #2
+";
+	let transcript: Transcript = other_actions.parse().unwrap();
+	assert_eq!(other_actions, transcript.to_string());
+
+	let card_text = "\
+Deck: Drunk, Confessor, Minion
+Draw: 1 villagers, 1 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: false
+Rules: card-text
+Reveal #1 Confessor {I confess to being Dizzy}
+Kill #1 => Drunk
+";
+	let transcript: Transcript = card_text.parse().unwrap();
+	assert_eq!(RuleSet::card_text(), transcript.rule_set);
+	assert_eq!(card_text, transcript.to_string());
+	let (state, _) = transcript.replay().unwrap();
+	assert_eq!(5, state.hitpoints());
+	assert!(matches!(
+		"Rules: made up".parse::<Transcript>(),
+		Err(NotationError::Line { line: 1, .. })
+	));
+	match "Night effects: flase".parse::<Transcript>() {
+		Err(NotationError::Line { line: 1, error }) => assert!(matches!(
+			*error,
+			NotationError::InvalidHeader("Night effects", ref value) if value == "flase"
+		)),
+		result => panic!("Expected an invalid header, got {:?}", result.map(|_| ())),
+	}
+
+	// a transcript parses with any deck, it's only checked when the game starts
+	let short_deck: Transcript = "\
+Deck: Lover, Minion
+Draw: 2 villagers, 0 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: false
+"
+	.parse()
+	.unwrap();
+	assert!(matches!(
+		short_deck.replay(),
+		Err(TranscriptError::InvalidDeck(
+			DeckError::NotEnoughArchetypes { .. }
+		))
+	));
+
+	assert!(matches!(
+		"Deck: Slayer\nReveal #0".parse::<Transcript>(),
+		Err(NotationError::Line { line: 2, .. })
+	));
+
+	let out_of_range: Transcript = "\
+Deck: Lover, Minion
+Draw: 1 villagers, 0 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: false
+Reveal #1 Lover {#3 is evil}
+"
+	.parse()
+	.unwrap();
+	assert!(matches!(
+		out_of_range.replay(),
+		Err(TranscriptError::Mutation(
+			GameStateMutationError::Testimony(TestimonyError::IndexOutOfRange {
+				index: VillagerIndex(2),
+				total_villagers: 2
+			})
+		))
+	));
+}
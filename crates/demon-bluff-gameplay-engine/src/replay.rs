@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::game_state::{Action, GameState, GameStateMutationError, GameStateMutationResult};
+
+#[derive(Error, Debug)]
+pub enum ReplayError {
+	#[error("Step {step} could not be applied: {error}")]
+	Mutation {
+		step: usize,
+		error: GameStateMutationError,
+	},
+	#[error("Step {step} resulted in {actual:?} but {expected:?} was recorded")]
+	ResultMismatch {
+		step: usize,
+		expected: GameStateMutationResult,
+		actual: GameStateMutationResult,
+	},
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayStep {
+	pub action: Action,
+	pub result: GameStateMutationResult,
+}
+
+/// A game that can be shared and played back. Serialized as JSON, the initial state followed by every action and what it resulted in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+	pub initial_state: GameState,
+	pub steps: Vec<ReplayStep>,
+}
+
+impl Replay {
+	/// Records every action in the state's history
+	pub fn from_state(state: &GameState) -> Self {
+		Self {
+			initial_state: state.initial_state(),
+			steps: state
+				.history()
+				.zip(state.history_results())
+				.map(|(action, result)| ReplayStep {
+					action: action.clone(),
+					result,
+				})
+				.collect(),
+		}
+	}
+
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+
+	pub fn from_json(json: &str) -> serde_json::Result<Self> {
+		serde_json::from_str(json)
+	}
+
+	/// Plays the steps back from the initial state, failing on the first one that doesn't go the way it was recorded
+	pub fn run(&self) -> Result<GameState, ReplayError> {
+		let mut state = self.initial_state.clone();
+		for (step, replay_step) in self.steps.iter().enumerate() {
+			let actual = state
+				.mutate(replay_step.action.clone())
+				.map_err(|error| ReplayError::Mutation { step, error })?;
+			if actual != replay_step.result {
+				return Err(ReplayError::ResultMismatch {
+					step,
+					expected: replay_step.result,
+					actual,
+				});
+			}
+		}
+
+		Ok(state)
+	}
+}
+
+#[test]
+fn test_replay_round_trip() {
+	use crate::{
+		Expression,
+		game_state::{DrawStats, KillAttempt, KillData, KillResult, RevealResult, new_game},
+		testimony::{ConfessorClaim, Testimony},
+		villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+	};
+
+	let mut state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(2, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+	state
+		.mutate(Action::TryReveal(RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Dizzy))),
+			)),
+		)))
+		.unwrap();
+	state
+		.mutate(Action::TryExecute(KillAttempt::new(
+			VillagerIndex(0),
+			Some(KillResult::Revealed(
+				KillData::new(Some(VillagerArchetype::Minion(Minion::Minion)), false).unwrap(),
+			)),
+		)))
+		.unwrap();
+
+	let replay = Replay::from_json(&Replay::from_state(&state).to_json().unwrap()).unwrap();
+	assert_eq!(2, replay.steps.len());
+	assert!(replay.initial_state.reveal_order().is_empty());
+	assert_eq!(GameStateMutationResult::Win, replay.steps[1].result);
+
+	let replayed_state = replay.run().unwrap();
+	assert_eq!(
+		format!("{:?}", state.villagers()),
+		format!("{:?}", replayed_state.villagers())
+	);
+
+	let mut tampered = replay.clone();
+	tampered.steps[1].result = GameStateMutationResult::Continue;
+	assert!(matches!(
+		tampered.run(),
+		Err(ReplayError::ResultMismatch { step: 1, .. })
+	));
+}
@@ -0,0 +1,284 @@
+use std::sync::{LazyLock, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use strum::EnumCount;
+use thiserror::Error;
+
+use crate::{
+	Expression,
+	affect::{Affect, NightEffect, VillagerAffect},
+	villager::{
+		Demon, ExecutionResult, GoodVillager, Minion, Outcast, VillagerArchetype, VillagerIndex,
+		other_indicies,
+	},
+};
+
+const BUILTIN_RULES: &str = include_str!("../rules/roles.json");
+
+static BUILTIN: LazyLock<RoleRegistry> = LazyLock::new(|| {
+	RoleRegistry::from_json(BUILTIN_RULES).expect("Builtin role rules are invalid")
+});
+
+static INSTALLED: OnceLock<RoleRegistry> = OnceLock::new();
+
+/// Which villagers an [`AffectDefinition`] lands on, relative to the one causing it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AffectTargets {
+	Pattern(Expression<VillagerAffect>),
+	AnyOther,
+}
+
+/// The data form of an [`Affect`], resolved against a board with [`AffectDefinition::resolve`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AffectDefinition {
+	Corrupt(AffectTargets),
+	Puppet(AffectTargets),
+	Night(NightEffect),
+	Outcast(AffectTargets),
+	DupeVillager,
+	FakeOutcast,
+	BlockLastNReveals(u8),
+}
+
+/// Everything the engines need to know about a [`VillagerArchetype`] that isn't how it testifies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleDefinition {
+	pub archetype: VillagerArchetype,
+	#[serde(default)]
+	pub evil: bool,
+	#[serde(default)]
+	pub appears_evil: bool,
+	#[serde(default)]
+	pub lies: bool,
+	#[serde(default)]
+	pub cannot_lie: bool,
+	#[serde(default)]
+	pub disguises: bool,
+	#[serde(default)]
+	pub can_be_disguised_as: bool,
+	#[serde(default)]
+	pub starts_corrupted: bool,
+	#[serde(default)]
+	pub can_be_converted: bool,
+	#[serde(default)]
+	pub can_be_corrupted: bool,
+	#[serde(default)]
+	pub has_night_action: bool,
+	#[serde(default)]
+	pub has_action: bool,
+	/// Another archetype that has to be in the deck for this one to be
+	#[serde(default)]
+	pub deck_prerequisite: Option<VillagerArchetype>,
+	#[serde(default)]
+	pub affect: Option<AffectDefinition>,
+	pub execution_result: ExecutionResult,
+}
+
+/// The [`RoleDefinition`] of every [`VillagerArchetype`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleRegistry {
+	roles: Vec<RoleDefinition>,
+}
+
+#[derive(Error, Debug)]
+pub enum RoleRegistryError {
+	#[error("Could not parse the role rules: {0}")]
+	Parse(#[from] serde_json::Error),
+	#[error("{0} is defined more than once")]
+	DuplicateRole(VillagerArchetype),
+	#[error("{0} is not defined")]
+	MissingRole(VillagerArchetype),
+	#[error("A role registry was already installed")]
+	AlreadyInstalled,
+}
+
+impl AffectTargets {
+	fn resolve(
+		&self,
+		total_villagers: usize,
+		index: &VillagerIndex,
+	) -> Option<Expression<VillagerAffect>> {
+		match self {
+			Self::Pattern(pattern) => Some(pattern.clone()),
+			Self::AnyOther => Expression::or_from_iterator(
+				other_indicies(index, total_villagers).map(|other_index| {
+					Expression::Leaf(VillagerAffect::from_index(
+						index,
+						&other_index,
+						total_villagers,
+					))
+				}),
+			),
+		}
+	}
+}
+
+impl AffectDefinition {
+	pub fn resolve(&self, total_villagers: usize, index: &VillagerIndex) -> Option<Affect> {
+		match self {
+			Self::Corrupt(targets) => targets.resolve(total_villagers, index).map(Affect::Corrupt),
+			Self::Puppet(targets) => targets.resolve(total_villagers, index).map(Affect::Puppet),
+			Self::Outcast(targets) => targets.resolve(total_villagers, index).map(Affect::Outcast),
+			Self::Night(night_effect) => Some(Affect::Night(night_effect.clone())),
+			Self::DupeVillager => Some(Affect::DupeVillager),
+			Self::FakeOutcast => Some(Affect::FakeOutcast),
+			Self::BlockLastNReveals(reveals) => Some(Affect::BlockLastNReveals(*reveals)),
+		}
+	}
+}
+
+impl RoleRegistry {
+	/// The roles as the game currently plays them
+	pub fn builtin() -> &'static Self {
+		&BUILTIN
+	}
+
+	/// The registry passed to [`RoleRegistry::install`], or [`RoleRegistry::builtin`] if there isn't one
+	pub fn current() -> &'static Self {
+		INSTALLED.get().unwrap_or_else(|| Self::builtin())
+	}
+
+	/// Parses a rules file defining every role exactly once
+	pub fn from_json(json: &str) -> Result<Self, RoleRegistryError> {
+		let definitions: Vec<RoleDefinition> = serde_json::from_str(json)?;
+		let mut roles: Vec<Option<RoleDefinition>> = vec![None; total_archetypes()];
+		for definition in definitions {
+			let slot = &mut roles[ordinal(&definition.archetype)];
+			if slot.is_some() {
+				return Err(RoleRegistryError::DuplicateRole(definition.archetype));
+			}
+
+			*slot = Some(definition);
+		}
+
+		let roles = roles
+			.into_iter()
+			.zip(VillagerArchetype::iter())
+			.map(|(definition, archetype)| {
+				definition.ok_or(RoleRegistryError::MissingRole(archetype))
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { roles })
+	}
+
+	/// Parses a rules file whose definitions replace the ones in this registry, i.e. a game patch changing a few roles
+	pub fn with_rules(&self, json: &str) -> Result<Self, RoleRegistryError> {
+		let definitions: Vec<RoleDefinition> = serde_json::from_str(json)?;
+		let mut patched = vec![false; total_archetypes()];
+		let mut registry = self.clone();
+		for definition in definitions {
+			let ordinal = ordinal(&definition.archetype);
+			if patched[ordinal] {
+				return Err(RoleRegistryError::DuplicateRole(definition.archetype));
+			}
+
+			patched[ordinal] = true;
+			registry.roles[ordinal] = definition;
+		}
+
+		Ok(registry)
+	}
+
+	/// Makes this the [`RoleRegistry::current`] registry for the rest of the process. Can only be done once
+	pub fn install(self) -> Result<(), RoleRegistryError> {
+		INSTALLED
+			.set(self)
+			.map_err(|_| RoleRegistryError::AlreadyInstalled)
+	}
+
+	pub fn role(&self, archetype: &VillagerArchetype) -> &RoleDefinition {
+		&self.roles[ordinal(archetype)]
+	}
+
+	pub fn roles(&self) -> impl Iterator<Item = &RoleDefinition> {
+		self.roles.iter()
+	}
+}
+
+fn total_archetypes() -> usize {
+	GoodVillager::COUNT + Outcast::COUNT + Minion::COUNT + Demon::COUNT
+}
+
+/// Position of the archetype in [`VillagerArchetype::iter`]
+fn ordinal(archetype: &VillagerArchetype) -> usize {
+	match archetype {
+		VillagerArchetype::GoodVillager(good_villager) => good_villager.clone() as usize,
+		VillagerArchetype::Outcast(outcast) => GoodVillager::COUNT + outcast.clone() as usize,
+		VillagerArchetype::Minion(minion) => {
+			GoodVillager::COUNT + Outcast::COUNT + minion.clone() as usize
+		}
+		VillagerArchetype::Demon(demon) => {
+			GoodVillager::COUNT + Outcast::COUNT + Minion::COUNT + demon.clone() as usize
+		}
+	}
+}
+
+#[test]
+fn test_builtin_roles() {
+	let registry = RoleRegistry::builtin();
+	for (definition, archetype) in registry.roles().zip(VillagerArchetype::iter()) {
+		assert_eq!(archetype, definition.archetype);
+	}
+
+	// The Drunk only deducts 2 health when executed. I don't know if it's a bug or what, but its what happens despite what their card says
+	let drunk = VillagerArchetype::Outcast(Outcast::Drunk);
+	assert_eq!(
+		ExecutionResult::HealthDeduction(2),
+		registry.role(&drunk).execution_result
+	);
+	assert_eq!(
+		Some(VillagerArchetype::Minion(Minion::Puppeteer)),
+		registry
+			.role(&VillagerArchetype::Minion(Minion::Puppet))
+			.deck_prerequisite
+	);
+}
+
+#[test]
+fn test_role_rules_patch() {
+	let drunk = VillagerArchetype::Outcast(Outcast::Drunk);
+	let patched = RoleRegistry::builtin()
+		.with_rules(
+			r#"[{
+				"archetype": { "Outcast": "Drunk" },
+				"lies": true,
+				"disguises": true,
+				"can_be_disguised_as": true,
+				"starts_corrupted": true,
+				"execution_result": { "HealthDeduction": 5 }
+			}]"#,
+		)
+		.expect("Patch should apply");
+	assert_eq!(
+		ExecutionResult::HealthDeduction(5),
+		patched.role(&drunk).execution_result
+	);
+	assert_eq!(
+		RoleRegistry::builtin().role(&VillagerArchetype::Demon(Demon::Lilis)),
+		patched.role(&VillagerArchetype::Demon(Demon::Lilis))
+	);
+
+	let duplicated = r#"[
+		{ "archetype": { "Demon": "Baa" }, "execution_result": "EvilKilled" },
+		{ "archetype": { "Demon": "Baa" }, "execution_result": "EvilKilled" }
+	]"#;
+	assert!(matches!(
+		RoleRegistry::builtin().with_rules(duplicated),
+		Err(RoleRegistryError::DuplicateRole(VillagerArchetype::Demon(
+			Demon::Baa
+		)))
+	));
+	assert!(matches!(
+		RoleRegistry::from_json(duplicated),
+		Err(RoleRegistryError::DuplicateRole(VillagerArchetype::Demon(
+			Demon::Baa
+		)))
+	));
+	assert!(matches!(
+		RoleRegistry::from_json("[]"),
+		Err(RoleRegistryError::MissingRole(
+			VillagerArchetype::GoodVillager(GoodVillager::Alchemist)
+		))
+	));
+}
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	roles::{AffectDefinition, RoleRegistry},
+	villager::{ConfirmedVillager, ExecutionResult, Minion, Outcast, VillagerArchetype},
+};
+
+/// What a Jester's evil count looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JesterRule {
+	/// Anything that appears evil counts, Wretches included
+	AppearsEvil,
+	/// Only actual evils count
+	IsEvil,
+}
+
+/// What a Judge sees when asked if someone is lying
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JudgeRule {
+	/// Anyone whose testimony is false, corrupted villagers included
+	WillLie,
+	/// Only roles that lie by nature
+	LyingRole,
+}
+
+/// The parts of a [`crate::roles::RoleDefinition`] a [`RuleSet`] can change. Anything left as `None` comes from the [`RoleRegistry::current`] role
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleOverride {
+	pub archetype: VillagerArchetype,
+	#[serde(default)]
+	pub execution_result: Option<ExecutionResult>,
+	/// How many of the last reveals the role blocks while alive. Only read for the Witch
+	#[serde(default)]
+	pub blocked_reveals: Option<u8>,
+}
+
+/// The behaviours that have changed between game updates. Games remember which they were played under so old saves still replay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+	pub name: String,
+	#[serde(default)]
+	pub role_overrides: Vec<RoleOverride>,
+	pub jester: JesterRule,
+	pub judge: JudgeRule,
+}
+
+impl RuleSet {
+	/// The rules of the game as it plays now
+	pub fn current() -> Self {
+		Self {
+			name: "current".to_string(),
+			role_overrides: Vec::new(),
+			jester: JesterRule::AppearsEvil,
+			judge: JudgeRule::WillLie,
+		}
+	}
+
+	/// The current rules, but executing the Drunk costs as much as any other villager like their card says
+	pub fn card_text() -> Self {
+		Self {
+			name: "card-text".to_string(),
+			role_overrides: vec![RoleOverride {
+				archetype: VillagerArchetype::Outcast(Outcast::Drunk),
+				execution_result: Some(ExecutionResult::HealthDeduction(5)),
+				blocked_reveals: None,
+			}],
+			..Self::current()
+		}
+	}
+
+	/// The rules before the Jester, Judge and Witch were reworked. Jesters and Judges only see what villagers really are and the Witch blocks the last two reveals
+	pub fn early_access() -> Self {
+		Self {
+			name: "early-access".to_string(),
+			role_overrides: vec![RoleOverride {
+				archetype: VillagerArchetype::Minion(Minion::Witch),
+				execution_result: None,
+				blocked_reveals: Some(2),
+			}],
+			jester: JesterRule::IsEvil,
+			judge: JudgeRule::LyingRole,
+		}
+	}
+
+	pub fn profiles() -> [Self; 3] {
+		[Self::current(), Self::card_text(), Self::early_access()]
+	}
+
+	/// The named profile from [`RuleSet::profiles`]
+	pub fn named(name: &str) -> Option<Self> {
+		Self::profiles()
+			.into_iter()
+			.find(|rule_set| rule_set.name == name)
+	}
+
+	fn role_override(&self, archetype: &VillagerArchetype) -> Option<&RoleOverride> {
+		self.role_overrides
+			.iter()
+			.find(|role_override| role_override.archetype == *archetype)
+	}
+
+	pub fn execution_result(&self, villager: &ConfirmedVillager) -> ExecutionResult {
+		let true_identity = villager.true_identity();
+		self.role_override(true_identity)
+			.and_then(|role_override| role_override.execution_result.clone())
+			.unwrap_or_else(|| {
+				RoleRegistry::current()
+					.role(true_identity)
+					.execution_result
+					.clone()
+			})
+	}
+
+	/// How many of the last reveals a living Witch blocks
+	pub fn witch_blocked_reveals(&self) -> usize {
+		let witch = VillagerArchetype::Minion(Minion::Witch);
+		if let Some(reveals) = self
+			.role_override(&witch)
+			.and_then(|role_override| role_override.blocked_reveals)
+		{
+			return reveals as usize;
+		}
+
+		match RoleRegistry::current().role(&witch).affect {
+			Some(AffectDefinition::BlockLastNReveals(reveals)) => reveals as usize,
+			_ => 0,
+		}
+	}
+}
+
+impl Default for RuleSet {
+	fn default() -> Self {
+		Self::current()
+	}
+}
+
+impl Display for RuleSet {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.name)
+	}
+}
+
+#[test]
+fn test_rule_set_execution_penalties() {
+	use crate::villager::{GoodVillager, VillagerInstance};
+
+	let executed = |archetype: VillagerArchetype| {
+		ConfirmedVillager::new(VillagerInstance::new(archetype, None), None, false)
+	};
+	let drunk = ConfirmedVillager::new(
+		VillagerInstance::new(VillagerArchetype::GoodVillager(GoodVillager::Knight), None),
+		Some(VillagerArchetype::Outcast(Outcast::Drunk)),
+		false,
+	);
+	let knight = executed(VillagerArchetype::GoodVillager(GoodVillager::Knight));
+	let bombardier = executed(VillagerArchetype::Outcast(Outcast::Bombardier));
+
+	let current = RuleSet::current();
+	assert_eq!(
+		ExecutionResult::HealthDeduction(2),
+		current.execution_result(&drunk)
+	);
+	assert_eq!(
+		ExecutionResult::HealthDeduction(5),
+		current.execution_result(&knight)
+	);
+
+	let card_text = RuleSet::named("card-text").expect("Missing profile");
+	assert_eq!(
+		ExecutionResult::HealthDeduction(5),
+		card_text.execution_result(&drunk)
+	);
+	assert_eq!(
+		ExecutionResult::SelfDestructKilled,
+		card_text.execution_result(&bombardier)
+	);
+	assert_eq!(None, RuleSet::named("made up"));
+	assert_eq!(1, card_text.witch_blocked_reveals());
+
+	let early_access = RuleSet::named("early-access").expect("Missing profile");
+	assert_eq!(2, early_access.witch_blocked_reveals());
+	assert_eq!(
+		ExecutionResult::HealthDeduction(2),
+		early_access.execution_result(&drunk)
+	);
+}
@@ -2,21 +2,49 @@ use std::{fmt::Display, num::NonZeroUsize};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, EnumString};
+use thiserror::Error;
 
 use crate::{
 	Expression,
 	villager::{GoodVillager, Outcast, VillagerArchetype, VillagerIndex},
 };
-const ALCHEMIST_CURE_RANGE: usize = 2;
+pub const ALCHEMIST_CURE_RANGE: usize = 2;
+
+/// Why a testimony can't be given on a board
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TestimonyError {
+	#[error("Villager numbers start at #1")]
+	VillagerNumberZero,
+	#[error("{index} is out of range for {total_villagers} villagers")]
+	IndexOutOfRange {
+		index: VillagerIndex,
+		total_villagers: usize,
+	},
+	#[error("{0} is targeted more than once")]
+	DuplicateTarget(VillagerIndex),
+	#[error("A Lover only sees 2 villagers, they can't have {0} evil neighbours")]
+	InvalidLoverEvils(usize),
+	#[error("A Jester only checks 3 villagers, they can't have found {0} evils")]
+	InvalidJesterEvils(usize),
+	#[error("A Hunter can't see {distance} villagers away with {total_villagers} villagers")]
+	InvalidHunterDistance {
+		distance: usize,
+		total_villagers: usize,
+	},
+}
 
-#[derive(Clone, Debug, PartialEq, Eq, Display, Serialize, Deserialize, Hash, PartialOrd, Ord)]
+#[derive(
+	Clone, Debug, PartialEq, Eq, Display, EnumString, Serialize, Deserialize, Hash, PartialOrd, Ord,
+)]
 pub enum ConfessorClaim {
 	Good,
 	Dizzy,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Display, Serialize, Deserialize, Hash, PartialOrd, Ord)]
+#[derive(
+	Clone, Debug, PartialEq, Eq, Display, EnumString, Serialize, Deserialize, Hash, PartialOrd, Ord,
+)]
 pub enum Direction {
 	Clockwise,
 	CounterClockwise,
@@ -128,7 +156,9 @@ impl Display for EvilPairsClaim {
 	}
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, PartialOrd, Ord, Display)]
+#[derive(
+	Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, PartialOrd, Ord, Display, EnumString,
+)]
 pub enum AffectType {
 	Puppeted,
 	CorruptedByEvil,
@@ -423,6 +453,7 @@ impl Testimony {
 		)))
 	}
 
+	/// Panics on more than 2 evils, see [`Testimony::try_lover`]
 	pub fn lover(
 		start_index: &VillagerIndex,
 		amount: usize,
@@ -520,6 +551,11 @@ impl Testimony {
 		)
 	}
 
+	pub fn poet(target: &VillagerIndex, archetype: VillagerArchetype) -> Expression<Testimony> {
+		Expression::Leaf(Testimony::Role(RoleClaim::new(target.clone(), archetype)))
+	}
+
+	/// Panics on more than 3 evils, see [`Testimony::try_jester`]
 	pub fn jester(targets: &[VillagerIndex; 3], evil_count: usize) -> Expression<Testimony> {
 		match evil_count {
 			0 => Expression::And(
@@ -613,6 +649,207 @@ impl Testimony {
 			_ => panic!("A jester can only have up to {} targets", targets.len()),
 		}
 	}
+
+	pub fn try_hunter(
+		start_index: &VillagerIndex,
+		distance: usize,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		start_index.validate(total_villagers)?;
+		if distance == 0 || distance > total_villagers / 2 {
+			return Err(TestimonyError::InvalidHunterDistance {
+				distance,
+				total_villagers,
+			});
+		}
+
+		Ok(Self::hunter(start_index, distance, total_villagers))
+	}
+
+	pub fn try_empress(
+		suspects: &[VillagerIndex; 3],
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		validate_targets(suspects, total_villagers)?;
+		Ok(Self::empress(suspects))
+	}
+
+	pub fn try_fortune_teller(
+		targets: &[VillagerIndex; 2],
+		evil: bool,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		validate_targets(targets, total_villagers)?;
+		Ok(Self::fortune_teller(targets, evil))
+	}
+
+	pub fn try_lover(
+		start_index: &VillagerIndex,
+		amount: usize,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		start_index.validate(total_villagers)?;
+		if amount > 2 {
+			return Err(TestimonyError::InvalidLoverEvils(amount));
+		}
+
+		Ok(Self::lover(start_index, amount, total_villagers))
+	}
+
+	pub fn try_oracle(
+		targets: &[VillagerIndex; 2],
+		archetype: VillagerArchetype,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		validate_targets(targets, total_villagers)?;
+		Ok(Self::oracle(targets, archetype))
+	}
+
+	pub fn try_poet(
+		target: &VillagerIndex,
+		archetype: VillagerArchetype,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		target.validate(total_villagers)?;
+		Ok(Self::poet(target, archetype))
+	}
+
+	pub fn try_jester(
+		targets: &[VillagerIndex; 3],
+		evil_count: usize,
+		total_villagers: usize,
+	) -> Result<Expression<Testimony>, TestimonyError> {
+		validate_targets(targets, total_villagers)?;
+		if evil_count > targets.len() {
+			return Err(TestimonyError::InvalidJesterEvils(evil_count));
+		}
+
+		Ok(Self::jester(targets, evil_count))
+	}
+
+	/// Checks every villager the testimony mentions is on a board of `total_villagers`
+	pub fn validate(&self, total_villagers: usize) -> Result<(), TestimonyError> {
+		match self {
+			Self::Good(index)
+			| Self::Evil(index)
+			| Self::Corrupt(index)
+			| Self::Lying(index)
+			| Self::Invincible(index)
+			| Self::FakeEvil(index)
+			| Self::SelfDestruct(index) => index.validate(total_villagers),
+			Self::Role(role_claim) => role_claim.index().validate(total_villagers),
+			Self::Affected(Some(affected_claim)) => {
+				affected_claim.index().validate(total_villagers)
+			}
+			Self::SlayAttempt(slay_result) => slay_result.index().validate(total_villagers),
+			Self::Dreamer(dreamer_claim) => dreamer_claim.target().validate(total_villagers),
+			Self::FortuneTeller(fortune_teller_claim) => {
+				validate_targets(fortune_teller_claim.targets(), total_villagers)
+			}
+			Self::Druid(druid_claim) => validate_targets(druid_claim.targets(), total_villagers),
+			Self::Bishop(bishop_claim) => validate_targets(bishop_claim.targets(), total_villagers),
+			Self::Cured(_)
+			| Self::Baker(_)
+			| Self::Affected(None)
+			| Self::Confess(_)
+			| Self::Scout(_)
+			| Self::Enlightened(_)
+			| Self::Knitter(_)
+			| Self::Bard(_)
+			| Self::Architect(_) => Ok(()),
+		}
+	}
+
+	/// [`Testimony::validate`] for every testimony in the expression
+	pub fn validate_expression(
+		expression: &Expression<Testimony>,
+		total_villagers: usize,
+	) -> Result<(), TestimonyError> {
+		expression
+			.leaves()
+			.into_iter()
+			.try_for_each(|testimony| testimony.validate(total_villagers))
+	}
+
+	/// Whether a revealed `archetype` at `index` could say this, truthfully or not. Only the shape is checked, see [`Testimony::validate_expression`] for the indices
+	pub fn matches_archetype(
+		expression: &Expression<Testimony>,
+		archetype: &VillagerArchetype,
+		index: &VillagerIndex,
+		total_villagers: usize,
+	) -> bool {
+		let leaves_are =
+			|predicate: fn(&Testimony) -> bool| expression.leaves().into_iter().all(predicate);
+		match archetype {
+			VillagerArchetype::GoodVillager(good_villager) => match good_villager {
+				GoodVillager::Alchemist => {
+					leaves_are(|testimony| matches!(testimony, Self::Cured(_)))
+				}
+				GoodVillager::Architect => {
+					leaves_are(|testimony| matches!(testimony, Self::Architect(_)))
+				}
+				GoodVillager::Baker => leaves_are(|testimony| matches!(testimony, Self::Baker(_))),
+				GoodVillager::Bard => leaves_are(|testimony| matches!(testimony, Self::Bard(_))),
+				GoodVillager::Bishop => {
+					leaves_are(|testimony| matches!(testimony, Self::Bishop(_)))
+				}
+				GoodVillager::Confessor => {
+					leaves_are(|testimony| matches!(testimony, Self::Confess(_)))
+				}
+				GoodVillager::Dreamer => {
+					leaves_are(|testimony| matches!(testimony, Self::Dreamer(_)))
+				}
+				GoodVillager::Druid => leaves_are(|testimony| matches!(testimony, Self::Druid(_))),
+				GoodVillager::Empress => {
+					leaves_are(|testimony| matches!(testimony, Self::Good(_) | Self::Evil(_)))
+				}
+				GoodVillager::Enlightened => {
+					leaves_are(|testimony| matches!(testimony, Self::Enlightened(_)))
+				}
+				GoodVillager::FortuneTeller => {
+					leaves_are(|testimony| matches!(testimony, Self::FortuneTeller(_)))
+				}
+				GoodVillager::Gemcrafter => {
+					leaves_are(|testimony| matches!(testimony, Self::Good(_)))
+				}
+				GoodVillager::Hunter => (1..=total_villagers / 2)
+					.any(|distance| *expression == Self::hunter(index, distance, total_villagers)),
+				GoodVillager::Jester => leaves_are(|testimony| matches!(testimony, Self::Evil(_))),
+				GoodVillager::Judge => leaves_are(|testimony| matches!(testimony, Self::Lying(_))),
+				GoodVillager::Knight => {
+					*expression == Expression::Leaf(Self::Invincible(index.clone()))
+				}
+				GoodVillager::Knitter => {
+					leaves_are(|testimony| matches!(testimony, Self::Knitter(_)))
+				}
+				GoodVillager::Lover => {
+					(0..=2).any(|amount| *expression == Self::lover(index, amount, total_villagers))
+				}
+				GoodVillager::Medium | GoodVillager::Oracle | GoodVillager::Poet => {
+					leaves_are(|testimony| matches!(testimony, Self::Role(_)))
+				}
+				GoodVillager::Scout => leaves_are(|testimony| matches!(testimony, Self::Scout(_))),
+				GoodVillager::Slayer => {
+					leaves_are(|testimony| matches!(testimony, Self::SlayAttempt(_)))
+				}
+				GoodVillager::Witness => {
+					leaves_are(|testimony| matches!(testimony, Self::Affected(_)))
+				}
+			},
+			VillagerArchetype::Outcast(outcast) => match outcast {
+				Outcast::Wretch => *expression == Expression::Leaf(Self::FakeEvil(index.clone())),
+				Outcast::Bombardier => {
+					*expression == Expression::Leaf(Self::SelfDestruct(index.clone()))
+				}
+				Outcast::PlagueDoctor => {
+					leaves_are(|testimony| matches!(testimony, Self::Corrupt(_)))
+				}
+				Outcast::Drunk | Outcast::Doppelganger => false,
+			},
+			// evils only ever testify disguised
+			VillagerArchetype::Minion(_) | VillagerArchetype::Demon(_) => false,
+		}
+	}
 }
 
 impl Display for Testimony {
@@ -654,7 +891,7 @@ impl Display for Testimony {
 			),
 			Self::Enlightened(direction) => write!(f, "Closest evil is {}", direction),
 			Self::Knitter(evil_pairs_claim) => {
-				write!(f, "There are {} pair(s) of evils", evil_pairs_claim)
+				write!(f, "There are {}", evil_pairs_claim)
 			}
 			Self::Bard(distance_option) => match distance_option {
 				Some(distance) => write!(
@@ -739,16 +976,26 @@ impl Display for Testimony {
 					None => Ok(()),
 				}
 			}
-			Self::Dreamer(dreamer_claim) => match &dreamer_claim.archetype {
-				Some(role) => {
-					write!(f, "{} is a {}", dreamer_claim.villager, role)
-				}
-				None => write!(f, "{} is a cabbage", dreamer_claim.villager),
-			},
+			// worded apart from a role claim so the two can be told apart when read back
+			Self::Dreamer(dreamer_claim) => write!(f, "I dreamt {}", dreamer_claim),
 		}
 	}
 }
 
+fn validate_targets(
+	targets: &[VillagerIndex],
+	total_villagers: usize,
+) -> Result<(), TestimonyError> {
+	for (position, target) in targets.iter().enumerate() {
+		target.validate(total_villagers)?;
+		if targets[..position].contains(target) {
+			return Err(TestimonyError::DuplicateTarget(target.clone()));
+		}
+	}
+
+	Ok(())
+}
+
 pub fn index_offset(
 	start_index: &VillagerIndex,
 	total_villagers: usize,
@@ -788,3 +1035,54 @@ fn test_hunter() {
 		Testimony::hunter(&VillagerIndex(1), 2, 5)
 	);
 }
+
+#[test]
+fn test_fallible_constructors() {
+	assert_eq!(
+		Err(TestimonyError::InvalidLoverEvils(3)),
+		Testimony::try_lover(&VillagerIndex(0), 3, 5)
+	);
+	assert_eq!(
+		Err(TestimonyError::InvalidJesterEvils(4)),
+		Testimony::try_jester(
+			&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(2)],
+			4,
+			5
+		)
+	);
+	assert_eq!(
+		Err(TestimonyError::DuplicateTarget(VillagerIndex(1))),
+		Testimony::try_jester(
+			&[VillagerIndex(0), VillagerIndex(1), VillagerIndex(1)],
+			1,
+			5
+		)
+	);
+	assert_eq!(
+		Err(TestimonyError::IndexOutOfRange {
+			index: VillagerIndex(5),
+			total_villagers: 5
+		}),
+		Testimony::try_poet(
+			&VillagerIndex(5),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			5
+		)
+	);
+	assert_eq!(
+		Err(TestimonyError::InvalidHunterDistance {
+			distance: 3,
+			total_villagers: 5
+		}),
+		Testimony::try_hunter(&VillagerIndex(1), 3, 5)
+	);
+	assert_eq!(
+		Ok(Testimony::hunter(&VillagerIndex(1), 2, 5)),
+		Testimony::try_hunter(&VillagerIndex(1), 2, 5)
+	);
+	assert_eq!(
+		Err(TestimonyError::VillagerNumberZero),
+		VillagerIndex::try_number(0)
+	);
+	assert_eq!(Ok(VillagerIndex(0)), VillagerIndex::try_number(1));
+}
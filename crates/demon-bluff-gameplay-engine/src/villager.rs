@@ -1,19 +1,31 @@
 use std::{arch::breakpoint, fmt::Display};
 
 use serde::{Deserialize, Serialize};
-use strum::{EnumIter, IntoEnumIterator};
+use strum::{EnumCount, EnumIter, IntoEnumIterator};
 
 use crate::{
 	Expression,
-	affect::{Affect, NightEffect, VillagerAffect},
-	testimony::{Direction, Testimony},
+	affect::Affect,
+	roles::{RoleDefinition, RoleRegistry},
+	testimony::{Testimony, TestimonyError},
 };
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct VillagerIndex(pub usize);
 
 #[derive(
-	Clone, Eq, PartialEq, Debug, Hash, Display, EnumIter, Serialize, Deserialize, PartialOrd, Ord,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+	Hash,
+	Display,
+	EnumIter,
+	EnumCount,
+	Serialize,
+	Deserialize,
+	PartialOrd,
+	Ord,
 )]
 pub enum GoodVillager {
 	Alchemist,
@@ -43,7 +55,18 @@ pub enum GoodVillager {
 }
 
 #[derive(
-	Clone, Eq, PartialEq, Debug, Hash, Display, EnumIter, Serialize, Deserialize, PartialOrd, Ord,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+	Hash,
+	Display,
+	EnumIter,
+	EnumCount,
+	Serialize,
+	Deserialize,
+	PartialOrd,
+	Ord,
 )]
 pub enum Outcast {
 	Drunk,
@@ -54,7 +77,18 @@ pub enum Outcast {
 }
 
 #[derive(
-	Clone, Eq, PartialEq, Debug, Hash, Display, EnumIter, Serialize, Deserialize, PartialOrd, Ord,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+	Hash,
+	Display,
+	EnumIter,
+	EnumCount,
+	Serialize,
+	Deserialize,
+	PartialOrd,
+	Ord,
 )]
 pub enum Minion {
 	Counsellor,
@@ -68,7 +102,18 @@ pub enum Minion {
 }
 
 #[derive(
-	Clone, Eq, PartialEq, Debug, Hash, Display, EnumIter, Serialize, Deserialize, PartialOrd, Ord,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+	Hash,
+	Display,
+	EnumIter,
+	EnumCount,
+	Serialize,
+	Deserialize,
+	PartialOrd,
+	Ord,
 )]
 pub enum Demon {
 	Baa,
@@ -118,6 +163,7 @@ pub enum Villager {
 	Confirmed(ConfirmedVillager),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExecutionResult {
 	EvilKilled,
 	SelfDestructKilled,
@@ -131,9 +177,29 @@ impl Display for VillagerIndex {
 }
 
 impl VillagerIndex {
+	/// Panics on `0`, see [`VillagerIndex::try_number`]
 	pub fn number(villager_number: usize) -> Self {
 		Self(villager_number - 1)
 	}
+
+	/// The index of the villager shown as `#villager_number`
+	pub fn try_number(villager_number: usize) -> Result<Self, TestimonyError> {
+		villager_number
+			.checked_sub(1)
+			.map(Self)
+			.ok_or(TestimonyError::VillagerNumberZero)
+	}
+
+	pub fn validate(&self, total_villagers: usize) -> Result<(), TestimonyError> {
+		if self.0 < total_villagers {
+			Ok(())
+		} else {
+			Err(TestimonyError::IndexOutOfRange {
+				index: self.clone(),
+				total_villagers,
+			})
+		}
+	}
 }
 
 impl VillagerArchetype {
@@ -145,628 +211,69 @@ impl VillagerArchetype {
 			.chain(Demon::iter().map(VillagerArchetype::Demon))
 	}
 
+	pub fn role(&self) -> &'static RoleDefinition {
+		RoleRegistry::current().role(self)
+	}
+
 	pub fn is_evil(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk
-				| Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => true,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => true,
-			},
-		}
+		self.role().evil
 	}
 
 	pub fn appears_evil(&self) -> bool {
-		VillagerArchetype::Outcast(Outcast::Wretch) == *self || self.is_evil()
+		self.role().appears_evil
 	}
 
 	/// This overrides lies for disguised archetypes
 	pub fn cannot_lie(&self) -> bool {
-		match self {
-			VillagerArchetype::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Confessor => true,
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			VillagerArchetype::Outcast(outcast) => match outcast {
-				Outcast::Drunk
-				| Outcast::Wretch
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor
-				| Outcast::Bombardier => false,
-			},
-			VillagerArchetype::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-			VillagerArchetype::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => false,
-			},
-		}
+		self.role().cannot_lie
 	}
 
 	pub fn lies(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk => true,
-				Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Puppet => false,
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer => true,
-			},
-			Self::Demon(_) => true,
-		}
+		self.role().lies
 	}
 
 	pub fn can_be_disguised_as(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => true,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Wretch => false,
-				Outcast::Drunk
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => true,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => false,
-			},
-		}
+		self.role().can_be_disguised_as
 	}
 
 	pub fn disguises(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk => true,
-				Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => true,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => true,
-			},
-		}
+		self.role().disguises
 	}
 
 	pub fn starts_corrupted(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk => true,
-				Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => false,
-			},
-		}
+		self.role().starts_corrupted
 	}
 
 	pub fn can_be_converted(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => true,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk
-				| Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Lilis | Demon::Baa | Demon::Pooka => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-		}
+		self.role().can_be_converted
 	}
 
 	pub fn can_be_corrupted(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => true,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::PlagueDoctor
-				| Outcast::Doppelganger
-				| Outcast::Drunk
-				| Outcast::Wretch
-				| Outcast::Bombardier => false,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Lilis | Demon::Baa | Demon::Pooka => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-		}
+		self.role().can_be_corrupted
 	}
 
 	pub fn has_night_action(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => false,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk
-				| Outcast::Wretch
-				| Outcast::Bombardier
-				| Outcast::Doppelganger
-				| Outcast::PlagueDoctor => false,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Lilis => true,
-				Demon::Baa | Demon::Pooka => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-		}
+		self.role().has_night_action
 	}
 
 	pub fn has_action(&self) -> bool {
-		match self {
-			Self::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Bard
-				| GoodVillager::Scout
-				| GoodVillager::Witness => false,
-				GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => true,
-			},
-			Self::Outcast(outcast) => match outcast {
-				Outcast::Drunk | Outcast::Wretch | Outcast::Bombardier | Outcast::Doppelganger => {
-					false
-				}
-				Outcast::PlagueDoctor => true,
-			},
-			Self::Demon(demon) => match demon {
-				Demon::Lilis | Demon::Baa | Demon::Pooka => false,
-			},
-			Self::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => false,
-			},
-		}
+		self.role().has_action
 	}
 
-	// this function only exists because of pupeteer so I'm taking the lazy route
 	pub fn deck_prerequisite(&self) -> VillagerArchetype {
-		match self {
-			Self::GoodVillager(good_villager) => Self::GoodVillager(good_villager.clone()),
-			Self::Outcast(outcast) => Self::Outcast(outcast.clone()),
-			Self::Minion(minion) => match minion {
-				Minion::Puppet => Self::Minion(Minion::Puppeteer),
-				other => Self::Minion(other.clone()),
-			},
-			Self::Demon(demon) => Self::Demon(demon.clone()),
-		}
+		self.role()
+			.deck_prerequisite
+			.clone()
+			.unwrap_or_else(|| self.clone())
 	}
 
-	/// index is only used to calculate positions of the [`VillagerAffect`]s if any
+	/// index is only used to calculate positions of the [`crate::affect::VillagerAffect`]s if any
 	pub fn affect(&self, total_villagers: usize, index: Option<VillagerIndex>) -> Option<Affect> {
 		let index = index.unwrap_or(VillagerIndex(0));
-		match self {
-			VillagerArchetype::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bard
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Slayer
-				| GoodVillager::Witness => None,
-			},
-			VillagerArchetype::Outcast(outcast) => match outcast {
-				Outcast::Drunk | Outcast::Wretch | Outcast::Bombardier | Outcast::Doppelganger => {
-					// A doppleganger is a disguise not a dupe
-					None
-				}
-				Outcast::PlagueDoctor => Expression::or_from_iterator(
-					other_indicies(&index, total_villagers).map(|other_index| {
-						Expression::Leaf(VillagerAffect::from_index(
-							&index,
-							&other_index,
-							total_villagers,
-						))
-					}),
-				)
-				.map(Affect::Corrupt),
-			},
-			VillagerArchetype::Minion(minion) => match minion {
-				Minion::Poisoner => Some(Affect::Corrupt(Expression::Or(
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::Clockwise,
-						1,
-					))),
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::CounterClockwise,
-						1,
-					))),
-				))),
-				Minion::Puppeteer => Some(Affect::Puppet(Expression::Or(
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::Clockwise,
-						1,
-					))),
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::CounterClockwise,
-						1,
-					))),
-				))),
-				Minion::Shaman => Some(Affect::DupeVillager),
-				Minion::Witch => Some(Affect::BlockLastNReveals(1)),
-				Minion::Counsellor => Some(Affect::Outcast(Expression::Or(
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::Clockwise,
-						1,
-					))),
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::CounterClockwise,
-						1,
-					))),
-				))),
-				Minion::Minion | Minion::Twinion | Minion::Puppet => None,
-			},
-			VillagerArchetype::Demon(demon) => Some(match demon {
-				Demon::Baa => Affect::FakeOutcast,
-				Demon::Pooka => Affect::Corrupt(Expression::And(
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::Clockwise,
-						1,
-					))),
-					Box::new(Expression::Leaf(VillagerAffect::new(
-						Direction::CounterClockwise,
-						1,
-					))),
-				)),
-				Demon::Lilis => Affect::Night(NightEffect::KillUnrevealed),
-			}),
-		}
+		self.role()
+			.affect
+			.as_ref()
+			.and_then(|affect| affect.resolve(total_villagers, &index))
 	}
 }
 
@@ -908,54 +415,7 @@ impl ConfirmedVillager {
 	}
 
 	pub fn execution_result(&self) -> ExecutionResult {
-		match self.true_identity() {
-			VillagerArchetype::GoodVillager(good_villager) => match good_villager {
-				GoodVillager::Alchemist
-				| GoodVillager::Architect
-				| GoodVillager::Baker
-				| GoodVillager::Bishop
-				| GoodVillager::Confessor
-				| GoodVillager::Empress
-				| GoodVillager::Enlightened
-				| GoodVillager::Gemcrafter
-				| GoodVillager::Hunter
-				| GoodVillager::Knight
-				| GoodVillager::Knitter
-				| GoodVillager::Lover
-				| GoodVillager::Medium
-				| GoodVillager::Oracle
-				| GoodVillager::Poet
-				| GoodVillager::Scout
-				| GoodVillager::Witness
-				| GoodVillager::Bard
-				| GoodVillager::Dreamer
-				| GoodVillager::Druid
-				| GoodVillager::FortuneTeller
-				| GoodVillager::Jester
-				| GoodVillager::Judge
-				| GoodVillager::Slayer => ExecutionResult::HealthDeduction(5),
-			},
-			VillagerArchetype::Outcast(outcast) => match outcast {
-				Outcast::Drunk => ExecutionResult::HealthDeduction(2), // I don't know if it's a bug or what, but its what happens despite what their card says
-				Outcast::Wretch | Outcast::Doppelganger | Outcast::PlagueDoctor => {
-					ExecutionResult::HealthDeduction(5)
-				}
-				Outcast::Bombardier => ExecutionResult::SelfDestructKilled,
-			},
-			VillagerArchetype::Minion(minion) => match minion {
-				Minion::Counsellor
-				| Minion::Witch
-				| Minion::Minion
-				| Minion::Poisoner
-				| Minion::Twinion
-				| Minion::Shaman
-				| Minion::Puppeteer
-				| Minion::Puppet => ExecutionResult::EvilKilled,
-			},
-			VillagerArchetype::Demon(demon) => match demon {
-				Demon::Baa | Demon::Pooka | Demon::Lilis => ExecutionResult::EvilKilled,
-			},
-		}
+		self.true_identity().role().execution_result.clone()
 	}
 }
 
@@ -0,0 +1,125 @@
+use std::{collections::HashMap, io::Write};
+
+use crate::{
+	build_board_layouts::BoardLayout,
+	build_expression_for_villager_set::{IndexTestimony, build_expression_for_villager_set},
+	cnf::Cnf,
+	optimized_expression::OptimizedExpression,
+	sat_backend::{SatBackend, SatBackendError},
+};
+
+/// The testimony expression of a [`BoardLayout`] in conjunctive normal form
+#[derive(Debug, Clone)]
+pub struct BoardLayoutCnf {
+	pub cnf: Cnf,
+	/// Variable `n` in the [`Cnf`] is `variables[n - 1]`
+	pub variables: Vec<IndexTestimony>,
+}
+
+impl BoardLayoutCnf {
+	/// [`None`] if no villager in the layout has a testimony
+	pub fn new(layout: &BoardLayout) -> Option<Self> {
+		let expression = build_expression_for_villager_set(
+			layout.villagers.iter().map(|villager| &villager.inner),
+		)?;
+		let optimized_expression = OptimizedExpression::new(&expression);
+		Some(Self {
+			cnf: optimized_expression.to_cnf(),
+			variables: optimized_expression.variables().to_vec(),
+		})
+	}
+
+	/// DIMACS CNF with a comment line mapping each variable to its [`IndexTestimony`]
+	pub fn write_dimacs(&self, writer: &mut impl Write) -> std::io::Result<()> {
+		writeln!(writer, "c demon bluff board layout testimonies")?;
+		self.cnf.write_named_dimacs(writer, &self.variables)
+	}
+
+	/// Every assignment of the testimonies that satisfies the layout, as found by the backend
+	pub fn satisfying_assignments(
+		&self,
+		backend: &impl SatBackend,
+	) -> Result<Vec<HashMap<IndexTestimony, bool>>, SatBackendError> {
+		Ok(backend
+			.enumerate_models(&self.cnf)?
+			.into_iter()
+			.map(|model| self.variables.iter().cloned().zip(model).collect())
+			.collect())
+	}
+}
+
+#[test]
+fn test_board_layout_dimacs() {
+	use std::collections::BTreeSet;
+
+	use demon_bluff_gameplay_engine::{
+		Expression,
+		testimony::Testimony,
+		villager::{
+			ConfirmedVillager, GoodVillager, Minion, VillagerArchetype, VillagerIndex,
+			VillagerInstance,
+		},
+	};
+
+	use crate::{build_board_layouts::TheoreticalVillager, sat_backend::BuiltinSatBackend};
+
+	let villager = |testimony: Testimony, true_identity: Option<VillagerArchetype>| {
+		TheoreticalVillager::new(
+			ConfirmedVillager::new(
+				VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Medium),
+					Some(Expression::Leaf(testimony)),
+				),
+				true_identity,
+				false,
+			),
+			false,
+			true,
+		)
+	};
+
+	let mut evil_locations = BTreeSet::new();
+	evil_locations.insert(VillagerIndex(1));
+	let layout = BoardLayout {
+		villagers: vec![
+			villager(Testimony::Good(VillagerIndex(1)), None),
+			villager(
+				Testimony::Good(VillagerIndex(0)),
+				Some(VillagerArchetype::Minion(Minion::Minion)),
+			),
+		],
+		evil_locations,
+		description: String::new(),
+	};
+
+	let layout_cnf = BoardLayoutCnf::new(&layout).expect("Layout has testimonies");
+	let mut dimacs = Vec::new();
+	layout_cnf.write_dimacs(&mut dimacs).unwrap();
+	let dimacs = String::from_utf8(dimacs).unwrap();
+
+	let mut lines = dimacs.lines();
+	assert_eq!(Some("c demon bluff board layout testimonies"), lines.next());
+	assert_eq!(
+		Some(format!("c 1 {}", layout_cnf.variables[0]).as_str()),
+		lines.next()
+	);
+	assert_eq!(
+		Some(format!("c 2 {}", layout_cnf.variables[1]).as_str()),
+		lines.next()
+	);
+	assert!(dimacs.lines().any(|line| line
+		== format!(
+			"p cnf {} {}",
+			layout_cnf.cnf.total_variables(),
+			layout_cnf.cnf.clauses().len()
+		)));
+
+	// the truthful testimony holds and the minion's is a lie
+	let assignments = layout_cnf
+		.satisfying_assignments(&BuiltinSatBackend)
+		.unwrap();
+	assert_eq!(1, assignments.len());
+	for (index_testimony, value) in &assignments[0] {
+		assert_eq!(index_testimony.index == VillagerIndex(0), *value);
+	}
+}
@@ -1,6 +1,6 @@
 use std::{
 	arch::breakpoint,
-	collections::{BTreeSet, HashMap, HashSet, hash_map::Entry},
+	collections::{BTreeSet, HashMap, hash_map::Entry},
 	str::FromStr,
 };
 
@@ -45,6 +45,17 @@ impl TheoreticalVillager {
 		!self.revealed
 			&& *self.inner.true_identity() == VillagerArchetype::GoodVillager(GoodVillager::Judge)
 	}
+
+	/// The archetype a hidden villager shows when revealed, if the layout pins it down. Evils, Drunks and unknown good villagers show a good villager we can't predict
+	pub fn shown_archetype(&self) -> Option<VillagerArchetype> {
+		if self.unknown_unrevealed_good()
+			|| self.inner.instance().archetype() != self.inner.true_identity()
+		{
+			None
+		} else {
+			Some(self.inner.true_identity().clone())
+		}
+	}
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Serialize, Deserialize)]
@@ -54,6 +65,41 @@ pub struct BoardLayout {
 	pub description: String,
 }
 
+#[cfg(test)]
+impl BoardLayout {
+	/// A layout of revealed villagers, each given as what they show, what they really are if that's different and whether they're corrupted
+	pub(crate) fn revealed(
+		villagers: impl IntoIterator<Item = (VillagerArchetype, Option<VillagerArchetype>, bool)>,
+	) -> Self {
+		let mut evil_locations = BTreeSet::new();
+		let villagers = villagers
+			.into_iter()
+			.enumerate()
+			.map(|(index, (archetype, true_identity, corrupted))| {
+				if true_identity.as_ref().unwrap_or(&archetype).is_evil() {
+					evil_locations.insert(VillagerIndex(index));
+				}
+
+				TheoreticalVillager::new(
+					ConfirmedVillager::new(
+						VillagerInstance::new(archetype, None),
+						true_identity,
+						corrupted,
+					),
+					false,
+					true,
+				)
+			})
+			.collect();
+
+		Self {
+			villagers,
+			evil_locations,
+			description: String::new(),
+		}
+	}
+}
+
 pub fn build_board_layouts(game_state: &GameState) -> DashSet<BoardLayout> {
 	let zone = span!("Build Board Layouts");
 
@@ -102,7 +148,13 @@ pub fn build_board_layouts(game_state: &GameState) -> DashSet<BoardLayout> {
 					outcast_count += 1;
 				}
 
-				remaining_initial_draw.retain(|deck_item| deck_item != true_identity);
+				// a deck can hold more than one copy of an archetype, this villager only accounts for one of them
+				if let Some(position) = remaining_initial_draw
+					.iter()
+					.position(|deck_item| deck_item == true_identity)
+				{
+					remaining_initial_draw.remove(position);
+				}
 			}
 		}
 		true
@@ -328,16 +380,7 @@ pub fn build_board_layouts(game_state: &GameState) -> DashSet<BoardLayout> {
 			Some(initial_layout)
 		})
 		.flat_map(|initial_layout| {
-			// TODO: Test pass order once deck builder mode releases
-			let wretch_spawned_theoreticals = with_wretch_locations(game_state, initial_layout);
-			let plague_doctor_spawned_theoreticals = wretch_spawned_theoreticals
-				.flat_map(|layout| with_real_plague_doctor_locations(game_state, layout));
-			let drunk_spawned_theoreticals = plague_doctor_spawned_theoreticals
-				.flat_map(|layout| with_real_drunk_locations(game_state, layout));
-			let alchemist_spawned_theoreticals = drunk_spawned_theoreticals
-				.flat_map(|layout| with_real_alchemist_locations(game_state, layout));
-			let dopple_spawned_theoreticals = alchemist_spawned_theoreticals
-				.flat_map(|layout| with_dopple_locations(game_state, layout));
+			let dopple_spawned_theoreticals = with_unrevealed_roles(game_state, initial_layout);
 			let adjacency_affected_theoreticals =
 				dopple_spawned_theoreticals.flat_map(with_adjacent_affects);
 			let pooka_affected_theoreticals =
@@ -366,6 +409,21 @@ pub fn build_board_layouts(game_state: &GameState) -> DashSet<BoardLayout> {
 	layouts
 }
 
+/// Places the deck's unrevealed outcasts, alchemists and dopples on a layout in every way the draw allows
+fn with_unrevealed_roles(
+	game_state: &GameState,
+	initial_layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	let wretch_spawned_theoreticals = with_wretch_locations(game_state, initial_layout);
+	let plague_doctor_spawned_theoreticals = wretch_spawned_theoreticals
+		.flat_map(|layout| with_real_plague_doctor_locations(game_state, layout));
+	let drunk_spawned_theoreticals = plague_doctor_spawned_theoreticals
+		.flat_map(|layout| with_real_drunk_locations(game_state, layout));
+	let alchemist_spawned_theoreticals = drunk_spawned_theoreticals
+		.flat_map(|layout| with_real_alchemist_locations(game_state, layout));
+	alchemist_spawned_theoreticals.flat_map(|layout| with_dopple_locations(game_state, layout))
+}
+
 gen fn with_adjacent_affects(layout: BoardLayout) -> BoardLayout {
 	let mut any_affects_applied = false;
 
@@ -672,181 +730,193 @@ gen fn with_plague_doctors_corruptions(layout: BoardLayout) -> BoardLayout {
 	}
 }
 
-gen fn with_real_plague_doctor_locations(
+/// How many copies of `archetype` in the deck aren't on the layout yet
+fn unplaced_copies(
 	game_state: &GameState,
-	layout: BoardLayout,
-) -> BoardLayout {
-	if game_state.role_in_play(VillagerArchetype::Outcast(Outcast::PlagueDoctor))
-		// this check is for if one was revealed already. There can only be one real PD
-		&& layout.villagers.iter().all(|villager| {
-			*villager.inner.true_identity() != VillagerArchetype::Outcast(Outcast::PlagueDoctor)
-		}) {
-		for index in 0..layout.villagers.len() {
-			let theoretical = &layout.villagers[index];
-			if !theoretical.inner.true_identity().is_evil()
-				&& !theoretical.inner.corrupted()
-				&& !theoretical.revealed
-			{
-				let mut next_layout = layout.clone();
-				next_layout.description = format!(
-					"{} - {} is unrevealed PD",
-					next_layout.description,
-					VillagerIndex(index)
-				);
-				next_layout.villagers[index].inner = ConfirmedVillager::new(
-					VillagerInstance::new(VillagerArchetype::Outcast(Outcast::PlagueDoctor), None),
-					None,
-					false,
-				);
-
-				yield next_layout;
-			}
-		}
-	}
-
-	// just in case the PD is fake
-	yield layout;
+	layout: &BoardLayout,
+	archetype: &VillagerArchetype,
+) -> usize {
+	let in_deck = game_state
+		.deck()
+		.iter()
+		.filter(|deck_archetype| *deck_archetype == archetype)
+		.count();
+	let placed = layout
+		.villagers
+		.iter()
+		.filter(|theoretical| theoretical.inner.true_identity() == archetype)
+		.count();
+	in_deck.saturating_sub(placed)
 }
 
-gen fn with_real_drunk_locations(game_state: &GameState, layout: BoardLayout) -> BoardLayout {
-	if game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Drunk))
-		// this check is for if one was revealed already. There can only be one real PD
-		&& layout.villagers.iter().all(|villager| {
-			*villager.inner.true_identity() != VillagerArchetype::Outcast(Outcast::Drunk)
-		}) {
-		for index in 0..layout.villagers.len() {
+/// Places the deck's copies of `archetype` that aren't on the layout yet on uncorrupted good villagers, in every set of locations `place` allows.
+/// `place` gives what a villager becomes with a copy there, or `None` if a copy can't go there
+gen fn with_copy_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+	archetype: VillagerArchetype,
+	label: &'static str,
+	first_index: usize,
+	place: fn(&TheoreticalVillager) -> Option<TheoreticalVillager>,
+) -> BoardLayout {
+	// revealed copies and the ones already placed use up the deck's copies
+	if unplaced_copies(game_state, &layout, &archetype) > 0 {
+		for index in first_index..layout.villagers.len() {
 			let theoretical = &layout.villagers[index];
-			if matches!(
+			if !matches!(
 				theoretical.inner.true_identity(),
 				VillagerArchetype::GoodVillager(_)
-			) && !theoretical.inner.corrupted()
+			) || theoretical.inner.corrupted()
 			{
-				let mut next_layout = layout.clone();
-				next_layout.description = format!(
-					"{} - {} is Drunk",
-					next_layout.description,
-					VillagerIndex(index)
-				);
-				next_layout.villagers[index].inner = ConfirmedVillager::new(
-					theoretical.inner.instance().clone(),
-					Some(VillagerArchetype::Outcast(Outcast::Drunk)),
-					true,
-				);
-
-				yield next_layout;
+				continue;
 			}
-		}
-	}
 
-	// just in case the drunk isn't drawn
-	yield layout;
-}
-
-gen fn with_wretch_locations(game_state: &GameState, layout: BoardLayout) -> BoardLayout {
-	if game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Wretch))
-		// this check is for if one was revealed already. There can only be one real PD
-		&& layout.villagers.iter().all(|villager| {
-			*villager.inner.true_identity() != VillagerArchetype::Outcast(Outcast::Wretch)
-		}) {
-		for index in 0..layout.villagers.len() {
-			let theoretical = &layout.villagers[index];
-			if !theoretical.inner.true_identity().is_evil()
-				&& !theoretical.inner.corrupted()
-				&& !theoretical.revealed
-			{
+			if let Some(placed) = place(theoretical) {
 				let mut next_layout = layout.clone();
 				next_layout.description = format!(
-					"{} - {} is unrevealed Wretch",
+					"{} - {} is {}",
 					next_layout.description,
-					VillagerIndex(index)
-				);
-				next_layout.villagers[index].inner = ConfirmedVillager::new(
-					VillagerInstance::new(VillagerArchetype::Outcast(Outcast::Wretch), None),
-					None,
-					false,
+					VillagerIndex(index),
+					label
 				);
-
-				yield next_layout;
+				next_layout.villagers[index] = placed;
+
+				// further copies only go to the right so each set of locations is built once
+				let with_further_copies: Box<dyn Iterator<Item = BoardLayout> + '_> =
+					Box::new(with_copy_locations(
+						game_state,
+						next_layout,
+						archetype.clone(),
+						label,
+						index + 1,
+						place,
+					));
+				for next_layout in with_further_copies {
+					yield next_layout;
+				}
 			}
 		}
 	}
 
-	// just in case the wretch wasn't drawn or whatever
+	// just in case no more copies were drawn
 	yield layout;
 }
 
-gen fn with_real_alchemist_locations(game_state: &GameState, layout: BoardLayout) -> BoardLayout {
-	if game_state.role_in_play(VillagerArchetype::GoodVillager(GoodVillager::Alchemist))
-		// this check is for if one was revealed. There can only be one initial alchemist
-		&& layout.villagers.iter().all(|villager| {
-			*villager.inner.true_identity() != VillagerArchetype::GoodVillager(GoodVillager::Alchemist)
-		}) {
-		for index in 0..layout.villagers.len() {
-			let theoretical = &layout.villagers[index];
-			if !theoretical.inner.true_identity().is_evil()
-				&& !theoretical.inner.corrupted()
-				&& !theoretical.revealed
-			{
-				let mut next_layout = layout.clone();
-				next_layout.description = format!(
-					"{} - {} is unrevealed Alchemist",
-					next_layout.description,
-					VillagerIndex(index)
-				);
-				next_layout.villagers[index].inner = ConfirmedVillager::new(
-					VillagerInstance::new(
-						VillagerArchetype::GoodVillager(GoodVillager::Alchemist),
-						None,
-					),
-					None,
-					false,
-				);
-
-				yield next_layout;
-			}
-		}
-	}
-
-	// just in case the alchemist is not present
-	yield layout;
+fn with_real_plague_doctor_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	with_copy_locations(
+		game_state,
+		layout,
+		VillagerArchetype::Outcast(Outcast::PlagueDoctor),
+		"unrevealed PD",
+		0,
+		|theoretical| {
+			let mut placed = theoretical.clone();
+			placed.inner = ConfirmedVillager::new(
+				VillagerInstance::new(VillagerArchetype::Outcast(Outcast::PlagueDoctor), None),
+				None,
+				false,
+			);
+			(!theoretical.revealed).then_some(placed)
+		},
+	)
 }
 
-gen fn with_dopple_locations(game_state: &GameState, layout: BoardLayout) -> BoardLayout {
-	if game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Doppelganger))
-		// this check is for if one was revealed. There can only be one initial dop
-		&& layout.villagers.iter().all(|villager| {
-			*villager.inner.true_identity() != VillagerArchetype::Outcast(Outcast::Doppelganger)
-		}) {
-		for index in 0..layout.villagers.len() {
-			let theoretical = &layout.villagers[index];
-			if matches!(theoretical.inner.true_identity(), VillagerArchetype::GoodVillager(_)) // dopple can't be outcast
-				&& !theoretical.inner.corrupted()
-			{
-				let mut next_layout = layout.clone();
-				next_layout.description = format!(
-					"{} - {} is doppled",
-					next_layout.description,
-					VillagerIndex(index)
-				);
+fn with_real_drunk_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	with_copy_locations(
+		game_state,
+		layout,
+		VillagerArchetype::Outcast(Outcast::Drunk),
+		"Drunk",
+		0,
+		|theoretical| {
+			let mut placed = theoretical.clone();
+			placed.inner = ConfirmedVillager::new(
+				theoretical.inner.instance().clone(),
+				Some(VillagerArchetype::Outcast(Outcast::Drunk)),
+				true,
+			);
+			Some(placed)
+		},
+	)
+}
 
-				let modified_villager = &mut next_layout.villagers[index];
-				modified_villager.inner = ConfirmedVillager::new(
-					modified_villager.inner.instance().clone(),
-					Some(VillagerArchetype::Outcast(Outcast::Doppelganger)),
-					false,
-				);
-				modified_villager.affection = Some(AffectType::Cloned);
+fn with_wretch_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	with_copy_locations(
+		game_state,
+		layout,
+		VillagerArchetype::Outcast(Outcast::Wretch),
+		"unrevealed Wretch",
+		0,
+		|theoretical| {
+			let mut placed = theoretical.clone();
+			placed.inner = ConfirmedVillager::new(
+				VillagerInstance::new(VillagerArchetype::Outcast(Outcast::Wretch), None),
+				None,
+				false,
+			);
+			(!theoretical.revealed).then_some(placed)
+		},
+	)
+}
 
-				yield next_layout;
-			}
-		}
-	}
+fn with_real_alchemist_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	with_copy_locations(
+		game_state,
+		layout,
+		VillagerArchetype::GoodVillager(GoodVillager::Alchemist),
+		"unrevealed Alchemist",
+		0,
+		|theoretical| {
+			let mut placed = theoretical.clone();
+			placed.inner = ConfirmedVillager::new(
+				VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Alchemist),
+					None,
+				),
+				None,
+				false,
+			);
+			(!theoretical.revealed).then_some(placed)
+		},
+	)
+}
 
-	// just in case the alchemist is not present
-	yield layout;
+fn with_dopple_locations(
+	game_state: &GameState,
+	layout: BoardLayout,
+) -> impl Iterator<Item = BoardLayout> {
+	with_copy_locations(
+		game_state,
+		layout,
+		VillagerArchetype::Outcast(Outcast::Doppelganger),
+		"doppled",
+		0,
+		|theoretical| {
+			let mut placed = theoretical.clone();
+			placed.inner = ConfirmedVillager::new(
+				theoretical.inner.instance().clone(),
+				Some(VillagerArchetype::Outcast(Outcast::Doppelganger)),
+				false,
+			);
+			placed.affection = Some(AffectType::Cloned);
+			Some(placed)
+		},
+	)
 }
 
-fn apply_alchemist_cures(mut layout: BoardLayout) -> BoardLayout {
+pub fn apply_alchemist_cures(mut layout: BoardLayout) -> BoardLayout {
 	// https://discord.com/channels/1148903384968089640/1400926599628460052/1414747887346389043
 	// "they go reverse numerical order except for doppels which act last"
 	// Cheers Autumn
@@ -964,7 +1034,7 @@ fn validate_board(game_state: &GameState, layout: &BoardLayout) -> bool {
 		return false;
 	}
 
-	let mut seen_good_villagers = HashSet::new();
+	let mut seen_good_villagers: HashMap<&GoodVillager, usize> = HashMap::new();
 	for theoretical in layout.villagers.iter() {
 		if !theoretical.revealed {
 			continue;
@@ -984,13 +1054,18 @@ fn validate_board(game_state: &GameState, layout: &BoardLayout) -> bool {
 				continue;
 			}
 
-			let mut seen_already = true;
-			seen_good_villagers.get_or_insert_with(good_villager, |good_villager| {
-				seen_already = false;
-				good_villager.clone()
-			});
-
-			if seen_already {
+			// only a deck holding the same villager more than once can put several of them in play
+			let copies_in_deck = game_state
+				.deck()
+				.iter()
+				.filter(|archetype| {
+					**archetype == VillagerArchetype::GoodVillager(good_villager.clone())
+				})
+				.count()
+				.max(1);
+			let seen = seen_good_villagers.entry(good_villager).or_default();
+			*seen += 1;
+			if *seen > copies_in_deck {
 				return false;
 			}
 		}
@@ -1033,3 +1108,129 @@ fn validate_board(game_state: &GameState, layout: &BoardLayout) -> bool {
 
 	true
 }
+
+#[test]
+fn test_duplicated_outcast_locations() {
+	use std::collections::HashSet;
+
+	use demon_bluff_gameplay_engine::game_state::{DrawStats, new_game};
+
+	let wretch = VillagerArchetype::Outcast(Outcast::Wretch);
+	let drunk = VillagerArchetype::Outcast(Outcast::Drunk);
+	let plague_doctor = VillagerArchetype::Outcast(Outcast::PlagueDoctor);
+	let game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Architect),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Enlightened),
+			wretch.clone(),
+			wretch.clone(),
+			drunk.clone(),
+			drunk.clone(),
+			plague_doctor.clone(),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(4, 5, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	// a revealed minion and four revealed villagers, the rest are unknown
+	let hidden_indicies = 5..10;
+	let villagers = [
+		(
+			GoodVillager::Confessor,
+			Some(VillagerArchetype::Minion(Minion::Minion)),
+		),
+		(GoodVillager::Gemcrafter, None),
+		(GoodVillager::Architect, None),
+		(GoodVillager::Medium, None),
+		(GoodVillager::Enlightened, None),
+	]
+	.into_iter()
+	.map(|(good_villager, true_identity)| {
+		TheoreticalVillager::new(
+			ConfirmedVillager::new(
+				VillagerInstance::new(VillagerArchetype::GoodVillager(good_villager), None),
+				true_identity,
+				false,
+			),
+			false,
+			true,
+		)
+	})
+	.chain(hidden_indicies.clone().map(|_| {
+		TheoreticalVillager::new(
+			ConfirmedVillager::new(
+				VillagerInstance::new(VillagerArchetype::GoodVillager(GoodVillager::Judge), None),
+				None,
+				false,
+			),
+			false,
+			false,
+		)
+	}))
+	.collect();
+	let initial_layout = BoardLayout {
+		villagers,
+		evil_locations: BTreeSet::from([VillagerIndex(0)]),
+		description: String::new(),
+	};
+
+	let layouts: Vec<BoardLayout> = with_unrevealed_roles(&game_state, initial_layout).collect();
+	let locations = |layout: &BoardLayout, archetype: &VillagerArchetype| {
+		layout
+			.villagers
+			.iter()
+			.enumerate()
+			.filter(|(_, theoretical)| theoretical.inner.true_identity() == archetype)
+			.map(|(index, _)| index)
+			.collect::<Vec<usize>>()
+	};
+
+	for layout in &layouts {
+		assert!(
+			locations(layout, &wretch).len() <= 2,
+			"{}",
+			layout.description
+		);
+		assert!(
+			locations(layout, &drunk).len() <= 2,
+			"{}",
+			layout.description
+		);
+		assert!(
+			locations(layout, &plague_doctor).len() <= 1,
+			"{}",
+			layout.description
+		);
+	}
+
+	// later passes don't overwrite earlier ones and copies aren't placed in another order
+	assert_eq!(
+		layouts
+			.iter()
+			.map(|layout| &layout.villagers)
+			.collect::<HashSet<_>>()
+			.len(),
+		layouts.len()
+	);
+
+	let wretch_locations: HashSet<Vec<usize>> = layouts
+		.iter()
+		.map(|layout| locations(layout, &wretch))
+		.collect();
+	let expected_wretch_locations: HashSet<Vec<usize>> = (0..=2)
+		.flat_map(|copies| hidden_indicies.clone().combinations(copies))
+		.collect();
+	assert_eq!(wretch_locations, expected_wretch_locations);
+
+	assert!(layouts.iter().any(|layout| {
+		locations(layout, &wretch).len() == 2
+			&& locations(layout, &drunk).len() == 2
+			&& locations(layout, &plague_doctor).len() == 1
+	}));
+}
@@ -5,8 +5,9 @@ use demon_bluff_gameplay_engine::{
 	testimony::{self, Testimony},
 	villager::{ConfirmedVillager, VillagerIndex},
 };
+use serde::Serialize;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub struct IndexTestimony {
 	pub index: VillagerIndex,
 	pub testimony: Testimony,
@@ -0,0 +1,93 @@
+use std::{fmt::Display, io::Write};
+
+/// A formula in conjunctive normal form using DIMACS literals: variable `n` (1-based) is `n` and its negation is `-n`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cnf {
+	/// The first `original_variables` variables map to the source expression's variables in order, the rest are auxiliary
+	original_variables: usize,
+	total_variables: usize,
+	clauses: Vec<Vec<i32>>,
+}
+
+impl Cnf {
+	pub fn new(original_variables: usize) -> Self {
+		Self {
+			original_variables,
+			total_variables: original_variables,
+			clauses: Vec::new(),
+		}
+	}
+
+	pub fn original_variables(&self) -> usize {
+		self.original_variables
+	}
+
+	pub fn total_variables(&self) -> usize {
+		self.total_variables
+	}
+
+	pub fn clauses(&self) -> &[Vec<i32>] {
+		&self.clauses
+	}
+
+	/// The DIMACS literal for a 0-based variable index
+	pub fn literal(variable_index: usize) -> i32 {
+		(variable_index + 1) as i32
+	}
+
+	pub fn new_auxiliary_variable(&mut self) -> i32 {
+		self.total_variables += 1;
+		self.total_variables as i32
+	}
+
+	pub fn add_clause(&mut self, clause: Vec<i32>) {
+		debug_assert!(clause.iter().all(
+			|literal| *literal != 0 && literal.unsigned_abs() as usize <= self.total_variables
+		));
+		self.clauses.push(clause);
+	}
+
+	/// Writes the formula in DIMACS CNF format
+	pub fn write_dimacs(&self, writer: &mut impl Write) -> std::io::Result<()> {
+		writeln!(
+			writer,
+			"p cnf {} {}",
+			self.total_variables,
+			self.clauses.len()
+		)?;
+		for clause in &self.clauses {
+			for literal in clause {
+				write!(writer, "{} ", literal)?;
+			}
+			writeln!(writer, "0")?;
+		}
+
+		Ok(())
+	}
+
+	/// [`Cnf::write_dimacs`] preceded by a comment line naming each original variable
+	pub fn write_named_dimacs<T>(
+		&self,
+		writer: &mut impl Write,
+		variables: &[T],
+	) -> std::io::Result<()>
+	where
+		T: Display,
+	{
+		debug_assert_eq!(self.original_variables, variables.len());
+		for (variable_index, variable) in variables.iter().enumerate() {
+			writeln!(writer, "c {} {}", Self::literal(variable_index), variable)?;
+		}
+
+		if self.total_variables > self.original_variables {
+			writeln!(
+				writer,
+				"c {}-{} auxiliary",
+				self.original_variables + 1,
+				self.total_variables
+			)?;
+		}
+
+		self.write_dimacs(writer)
+	}
+}
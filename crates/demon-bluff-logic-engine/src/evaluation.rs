@@ -0,0 +1,266 @@
+use std::fmt::Display;
+
+use demon_bluff_gameplay_engine::{game_state::DrawStats, villager::VillagerArchetype};
+use log::{Log, info};
+use serde::Serialize;
+use tracy_client::span;
+
+use crate::{
+	RevealStrategy,
+	simulator::{Simulation, SimulationError},
+};
+
+/// A deck and draw to deal simulated games from
+#[derive(Debug, Clone, Serialize)]
+pub struct DeckConfiguration {
+	pub name: String,
+	pub deck: Vec<VillagerArchetype>,
+	pub draw_stats: DrawStats,
+}
+
+/// Aggregated results of one [`RevealStrategy`] over the games of one [`DeckConfiguration`]
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyEvaluation {
+	pub configuration: String,
+	pub strategy: RevealStrategy,
+	pub games: usize,
+	pub wins: usize,
+	pub losses: usize,
+	/// Games that ended because [`crate::predict`] returned an error
+	pub prediction_errors: usize,
+	/// Games that ended because the simulation itself failed
+	pub simulation_errors: usize,
+	/// Wins over all games, errors count against it
+	pub win_rate: f64,
+	/// Averaged over the games that finished
+	pub average_hitpoints_lost: f64,
+	/// Averaged over the games that finished
+	pub average_actions: f64,
+	pub average_prediction_milliseconds: f64,
+	/// Seeds of the games that errored, see [`crate::SimulationRecord`]
+	pub failed_seeds: Vec<u64>,
+}
+
+/// The results of [`evaluate_strategies`]. [`Display`] renders it as a table
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationReport {
+	pub evaluations: Vec<StrategyEvaluation>,
+}
+
+impl EvaluationReport {
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+}
+
+impl Display for EvaluationReport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(
+			f,
+			"{:<24} {:<16} {:>6} {:>6} {:>9} {:>12} {:>12} {:>15} {:>18} {:>18}",
+			"Configuration",
+			"Strategy",
+			"Games",
+			"Wins",
+			"Win Rate",
+			"Avg HP Lost",
+			"Avg Actions",
+			"Avg Predict ms",
+			"Prediction Errors",
+			"Simulation Errors"
+		)?;
+
+		for evaluation in &self.evaluations {
+			writeln!(
+				f,
+				"{:<24} {:<16} {:>6} {:>6} {:>8.1}% {:>12.2} {:>12.2} {:>15.2} {:>18} {:>18}",
+				evaluation.configuration,
+				format!("{:?}", evaluation.strategy),
+				evaluation.games,
+				evaluation.wins,
+				evaluation.win_rate * 100.0,
+				evaluation.average_hitpoints_lost,
+				evaluation.average_actions,
+				evaluation.average_prediction_milliseconds,
+				evaluation.prediction_errors,
+				evaluation.simulation_errors
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Plays `games` simulated games of each configuration with every strategy.
+/// Game `n` of a configuration is dealt from `first_seed + n` so each strategy faces the same boards
+pub fn evaluate_strategies(
+	log: &impl Log,
+	configurations: &[DeckConfiguration],
+	strategies: &[RevealStrategy],
+	games: usize,
+	first_seed: u64,
+) -> Result<EvaluationReport, SimulationError> {
+	let zone = span!("Evaluate Strategies");
+	let mut evaluations = Vec::with_capacity(configurations.len() * strategies.len());
+	for configuration in configurations {
+		for strategy in strategies {
+			info!(logger: log, "Evaluating {:?} on {}", strategy, configuration.name);
+			evaluations.push(evaluate_strategy(
+				log,
+				configuration,
+				*strategy,
+				games,
+				first_seed,
+			)?);
+		}
+	}
+
+	Ok(EvaluationReport { evaluations })
+}
+
+fn evaluate_strategy(
+	log: &impl Log,
+	configuration: &DeckConfiguration,
+	strategy: RevealStrategy,
+	games: usize,
+	first_seed: u64,
+) -> Result<StrategyEvaluation, SimulationError> {
+	let mut wins = 0;
+	let mut losses = 0;
+	let mut prediction_errors = 0;
+	let mut simulation_errors = 0;
+	let mut total_hitpoints_lost = 0;
+	let mut total_actions = 0;
+	let mut predictions = 0;
+	let mut prediction_seconds = 0.0;
+	let mut failed_seeds = Vec::new();
+	for game in 0..games {
+		let seed = first_seed + game as u64;
+
+		// a deck that can't be dealt is a bad configuration, not a strategy failure
+		let mut simulation = Simulation::deal(
+			configuration.deck.clone(),
+			configuration.draw_stats.clone(),
+			seed,
+		)?;
+		let starting_hitpoints = simulation.state().hitpoints();
+		let result = simulation.play(log, strategy);
+		predictions += simulation.predictions();
+		prediction_seconds += simulation.prediction_time().as_secs_f64();
+		match result {
+			Ok(outcome) => {
+				if outcome.won {
+					wins += 1;
+				} else {
+					losses += 1;
+				}
+
+				total_hitpoints_lost += (starting_hitpoints - outcome.hitpoints) as usize;
+				total_actions += outcome.actions;
+			}
+			Err(error) => {
+				info!(logger: log, "Seed {} failed: {}", seed, error);
+				if let SimulationError::Prediction(_) = error {
+					prediction_errors += 1;
+				} else {
+					simulation_errors += 1;
+				}
+
+				failed_seeds.push(seed);
+			}
+		}
+	}
+
+	let average = |total: f64, count: usize| {
+		if count == 0 {
+			0.0
+		} else {
+			total / count as f64
+		}
+	};
+	let finished_games = wins + losses;
+	Ok(StrategyEvaluation {
+		configuration: configuration.name.clone(),
+		strategy,
+		games,
+		wins,
+		losses,
+		prediction_errors,
+		simulation_errors,
+		win_rate: average(wins as f64, games),
+		average_hitpoints_lost: average(total_hitpoints_lost as f64, finished_games),
+		average_actions: average(total_actions as f64, finished_games),
+		average_prediction_milliseconds: average(prediction_seconds * 1000.0, predictions),
+		failed_seeds,
+	})
+}
+
+#[test]
+fn test_evaluate_strategies() {
+	use demon_bluff_gameplay_engine::villager::{Demon, GoodVillager, Minion};
+
+	let configuration = DeckConfiguration {
+		name: "Minion and Pooka".to_string(),
+		deck: vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::Minion(Minion::Minion),
+			VillagerArchetype::Demon(Demon::Pooka),
+		],
+		draw_stats: DrawStats::new(4, 0, 1, 1),
+	};
+
+	let log = log::logger();
+	let report = evaluate_strategies(
+		&log,
+		&[configuration],
+		&[
+			RevealStrategy::Simple,
+			RevealStrategy::FollowTestimony,
+			RevealStrategy::InformationGain,
+		],
+		2,
+		0,
+	)
+	.unwrap();
+
+	assert_eq!(3, report.evaluations.len());
+	for evaluation in &report.evaluations {
+		assert_eq!(2, evaluation.games);
+		assert_eq!(
+			evaluation.games,
+			evaluation.wins
+				+ evaluation.losses
+				+ evaluation.prediction_errors
+				+ evaluation.simulation_errors
+		);
+		assert_eq!(
+			evaluation.prediction_errors + evaluation.simulation_errors,
+			evaluation.failed_seeds.len()
+		);
+	}
+
+	let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+	assert_eq!(
+		"FollowTestimony",
+		json["evaluations"][1]["strategy"].as_str().unwrap()
+	);
+	assert_eq!(
+		"InformationGain",
+		json["evaluations"][2]["strategy"].as_str().unwrap()
+	);
+
+	let table = report.to_string();
+	assert_eq!(4, table.lines().count());
+	assert!(
+		table
+			.lines()
+			.nth(2)
+			.unwrap()
+			.starts_with("Minion and Pooka")
+	);
+}
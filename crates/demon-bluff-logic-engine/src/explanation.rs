@@ -0,0 +1,247 @@
+use std::{
+	collections::{BTreeSet, HashSet},
+	fmt::Display,
+};
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::GameState,
+	villager::VillagerIndex,
+};
+use log::Log;
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use serde::Serialize;
+use tracy_client::span;
+
+use crate::{
+	PlayerAction, PredictionError, RevealStrategy, RolesInPlay,
+	build_board_layouts::{BoardLayout, build_board_layouts},
+	build_expression_for_villager_set::{IndexTestimony, build_expression_for_villager_set},
+	expression_assertion::collect_satisfying_assignments,
+	first_invalid_testimony,
+	optimized_expression::OptimizedExpression,
+	predict,
+};
+
+/// A [`BoardLayout`] that is consistent with every testimony
+#[derive(Debug, Clone, Serialize)]
+pub struct SurvivingLayout {
+	pub description: String,
+	pub evil_locations: BTreeSet<VillagerIndex>,
+	/// Testimonies that are false in every way the layout can be satisfied
+	pub lies: BTreeSet<IndexTestimony>,
+	/// Testimonies that are false in some, but not all, of the ways the layout can be satisfied
+	pub possible_lies: BTreeSet<IndexTestimony>,
+}
+
+/// A [`BoardLayout`] that the testimonies ruled out
+#[derive(Debug, Clone, Serialize)]
+pub struct EliminatedLayout {
+	pub description: String,
+	pub evil_locations: BTreeSet<VillagerIndex>,
+	/// The testimony that contradicted the layout, for each way its expression could otherwise be satisfied.
+	/// Empty if the layout's expression can't be satisfied at all
+	pub eliminated_by: BTreeSet<IndexTestimony>,
+}
+
+/// The reasoning behind a prediction, split into the layouts still in the running and the ones ruled out
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Explanation {
+	pub surviving_layouts: Vec<SurvivingLayout>,
+	pub eliminated_layouts: Vec<EliminatedLayout>,
+}
+
+impl Display for Explanation {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "{} surviving layouts:", self.surviving_layouts.len())?;
+		for layout in &self.surviving_layouts {
+			writeln!(f, "- {}", layout.description)?;
+			for lie in &layout.lies {
+				writeln!(f, "  Lie: {}", lie)?;
+			}
+			for possible_lie in &layout.possible_lies {
+				writeln!(f, "  Possible lie: {}", possible_lie)?;
+			}
+		}
+
+		writeln!(f, "{} eliminated layouts:", self.eliminated_layouts.len())?;
+		for layout in &self.eliminated_layouts {
+			writeln!(f, "- {}", layout.description)?;
+			if layout.eliminated_by.is_empty() {
+				writeln!(f, "  Testimonies contradict each other")?;
+			}
+			for index_testimony in &layout.eliminated_by {
+				writeln!(f, "  Contradicted by: {}", index_testimony)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// [`predict`] along with the [`Explanation`] of the state it was made from.
+/// The explanation is still produced if the prediction fails
+pub fn predict_with_explanation(
+	log: &impl Log,
+	state: &GameState,
+	reveal_strategy: RevealStrategy,
+) -> (Result<HashSet<PlayerAction>, PredictionError>, Explanation) {
+	(predict(log, state, reveal_strategy), explain(log, state))
+}
+
+/// Checks every [`BoardLayout`] the deck allows against the testimonies given so far
+pub fn explain(log: &impl Log, state: &GameState) -> Explanation {
+	let zone = span!("Explain");
+	let layouts: Vec<BoardLayout> = build_board_layouts(state).into_iter().collect();
+	let expressions: Vec<Option<Expression<IndexTestimony>>> = layouts
+		.iter()
+		.map(|layout| {
+			build_expression_for_villager_set(
+				layout
+					.villagers
+					.iter()
+					.map(|theoretical| &theoretical.inner),
+			)
+		})
+		.collect();
+
+	let mut layout_expressions: Vec<Expression<IndexTestimony>> =
+		expressions.iter().flatten().cloned().collect();
+	let master_expression = match layout_expressions.len() {
+		// nobody has testified, nothing can be ruled out
+		0 => {
+			return Explanation {
+				surviving_layouts: layouts
+					.into_iter()
+					.map(|layout| SurvivingLayout {
+						description: layout.description,
+						evil_locations: layout.evil_locations,
+						lies: BTreeSet::new(),
+						possible_lies: BTreeSet::new(),
+					})
+					.collect(),
+				eliminated_layouts: Vec::new(),
+			};
+		}
+		1 => layout_expressions.pop().unwrap(),
+		_ => Expression::MajorOr(layout_expressions),
+	};
+
+	let optimized_master_expression = OptimizedExpression::new(&master_expression);
+	let master_assignments = collect_satisfying_assignments(&optimized_master_expression);
+
+	let roles_in_play = RolesInPlay::new(state);
+
+	let results: Vec<Result<SurvivingLayout, EliminatedLayout>> = (0..layouts.len())
+		.into_par_iter()
+		.map(|layout_index| {
+			let layout = &layouts[layout_index];
+			let Some(expression) = &expressions[layout_index] else {
+				return Ok(SurvivingLayout {
+					description: layout.description.clone(),
+					evil_locations: layout.evil_locations.clone(),
+					lies: BTreeSet::new(),
+					possible_lies: BTreeSet::new(),
+				});
+			};
+
+			let optimized_expression = OptimizedExpression::new(expression);
+
+			// the layout's testimonies are a subset of the master expression's, but not necessarily in the same order
+			let master_variable_indicies: Vec<usize> = optimized_expression
+				.variables()
+				.iter()
+				.map(|variable| {
+					optimized_master_expression
+						.variables()
+						.iter()
+						.position(|master_variable| master_variable == variable)
+						.expect("Layout testimony missing from the master expression")
+				})
+				.collect();
+
+			let mut satisfied = false;
+			let mut ever_true = vec![false; master_variable_indicies.len()];
+			let mut ever_false = vec![false; master_variable_indicies.len()];
+			let mut eliminated_by = BTreeSet::new();
+			for master_assignment in &master_assignments {
+				let assignment: Vec<bool> = master_variable_indicies
+					.iter()
+					.map(|master_variable_index| master_assignment[*master_variable_index])
+					.collect();
+				if !optimized_expression.satisfies(|variable_index| assignment[variable_index]) {
+					continue;
+				}
+
+				match first_invalid_testimony(
+					log,
+					&assignment,
+					optimized_expression.variables(),
+					layout,
+					state,
+					roles_in_play,
+				) {
+					Some(variable_index) => {
+						eliminated_by
+							.insert(optimized_expression.variables()[variable_index].clone());
+					}
+					None => {
+						satisfied = true;
+						for (variable_index, truthful) in assignment.iter().enumerate() {
+							if *truthful {
+								ever_true[variable_index] = true;
+							} else {
+								ever_false[variable_index] = true;
+							}
+						}
+					}
+				}
+			}
+
+			if !satisfied {
+				return Err(EliminatedLayout {
+					description: layout.description.clone(),
+					evil_locations: layout.evil_locations.clone(),
+					eliminated_by,
+				});
+			}
+
+			let mut lies = BTreeSet::new();
+			let mut possible_lies = BTreeSet::new();
+			for (variable_index, variable) in optimized_expression.variables().iter().enumerate() {
+				if !ever_false[variable_index] {
+					continue;
+				}
+
+				if ever_true[variable_index] {
+					possible_lies.insert(variable.clone());
+				} else {
+					lies.insert(variable.clone());
+				}
+			}
+
+			Ok(SurvivingLayout {
+				description: layout.description.clone(),
+				evil_locations: layout.evil_locations.clone(),
+				lies,
+				possible_lies,
+			})
+		})
+		.collect();
+
+	let mut explanation = Explanation::default();
+	for result in results {
+		match result {
+			Ok(surviving_layout) => explanation.surviving_layouts.push(surviving_layout),
+			Err(eliminated_layout) => explanation.eliminated_layouts.push(eliminated_layout),
+		}
+	}
+
+	explanation
+		.surviving_layouts
+		.sort_by(|lhs, rhs| lhs.description.cmp(&rhs.description));
+	explanation
+		.eliminated_layouts
+		.sort_by(|lhs, rhs| lhs.description.cmp(&rhs.description));
+	explanation
+}
@@ -6,44 +6,19 @@ use std::{
 };
 
 use demon_bluff_gameplay_engine::Expression;
-use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-use crate::optimized_expression::OptimizedExpression;
+use crate::{optimized_expression::OptimizedExpression, sat_solver::Solver};
 
 pub fn collect_satisfying_assignments<T>(expression: &OptimizedExpression<T>) -> Vec<Vec<bool>>
 where
 	T: Display + Hash + Eq + Clone + Send + Sync,
 {
-	// Generate all possible assignments (2^n where n is number of variables)
-	let num_vars = expression.variables().len();
-	let total_potential_assignments: u64 = 1 << num_vars;
-
-	if total_potential_assignments > u32::MAX as u64 {
-		panic!(
-			"We should never have more than 2G potential assignments: {}",
-			total_potential_assignments
-		);
-	}
-
-	let assignments = (0..total_potential_assignments)
-		.into_par_iter()
-		.filter_map(|i| {
-			let mut assignment = Vec::new();
-
-			for (j, _) in expression.variables().iter().enumerate() {
-				assignment.push((i & (1 << j)) != 0);
-			}
+	let cnf = expression.to_cnf();
+	let mut models = Solver::new(&cnf).enumerate_models(cnf.original_variables());
 
-			// Check if this assignment satisfies the expression
-			if expression.satisfies(|variable_index| assignment[variable_index]) {
-				Some(assignment)
-			} else {
-				None
-			}
-		})
-		.collect();
-
-	assignments
+	// keep the order brute forcing used to produce, with the first variable as the lowest bit
+	models.sort_unstable_by(|lhs, rhs| lhs.iter().rev().cmp(rhs.iter().rev()));
+	models
 }
 
 fn collect_variables<T>(expression: &Expression<T>) -> HashSet<T>
@@ -108,3 +83,89 @@ where
 		}
 	}
 }
+
+#[cfg(test)]
+fn brute_force_satisfying_assignments<T>(expression: &OptimizedExpression<T>) -> Vec<Vec<bool>>
+where
+	T: Eq + Clone,
+{
+	let num_vars = expression.variables().len();
+	(0..(1u64 << num_vars))
+		.map(|i| (0..num_vars).map(|j| (i & (1 << j)) != 0).collect())
+		.filter(|assignment: &Vec<bool>| {
+			expression.satisfies(|variable_index| assignment[variable_index])
+		})
+		.collect()
+}
+
+#[test]
+fn test_satisfying_assignments_match_brute_force() {
+	let leaf = |name: &str| Expression::Leaf(name.to_owned());
+	let not = |expression| Expression::Not(Box::new(expression));
+	let and = |lhs, rhs| Expression::And(Box::new(lhs), Box::new(rhs));
+	let or = |lhs, rhs| Expression::Or(Box::new(lhs), Box::new(rhs));
+
+	let expressions = vec![
+		leaf("a"),
+		not(leaf("a")),
+		and(leaf("a"), not(leaf("a"))),
+		or(leaf("a"), not(leaf("a"))),
+		and(
+			or(not(leaf("a")), not(leaf("b"))),
+			or(not(leaf("c")), not(leaf("a"))),
+		),
+		Expression::MajorOr(vec![
+			and(leaf("a"), and(leaf("b"), not(leaf("c")))),
+			and(not(leaf("a")), or(leaf("c"), leaf("d"))),
+			not(or(leaf("b"), and(leaf("d"), leaf("e")))),
+		]),
+		and(
+			Expression::MajorOr(vec![
+				and(leaf("a"), not(leaf("b"))),
+				and(leaf("b"), not(leaf("c"))),
+				and(leaf("c"), not(leaf("d"))),
+				and(leaf("d"), not(leaf("e"))),
+			]),
+			and(
+				or(leaf("a"), leaf("e")),
+				not(and(leaf("f"), or(leaf("g"), not(leaf("a"))))),
+			),
+		),
+	];
+
+	for expression in expressions {
+		let optimized_expression = OptimizedExpression::new(&expression);
+		assert_eq!(
+			brute_force_satisfying_assignments(&optimized_expression),
+			collect_satisfying_assignments(&optimized_expression),
+			"Mismatch for {}",
+			expression
+		);
+	}
+}
+
+#[test]
+fn test_many_testimonies() {
+	// 40 chained xors would take 2^40 checks to brute force, but only have 2 models
+	let variables: Vec<String> = (0..40).map(|index| format!("t{}", index)).collect();
+	let expression = Expression::and_from_iterator(variables.windows(2).map(|pair| {
+		Expression::Or(
+			Box::new(Expression::And(
+				Box::new(Expression::Leaf(pair[0].clone())),
+				Box::new(Expression::Not(Box::new(Expression::Leaf(pair[1].clone())))),
+			)),
+			Box::new(Expression::And(
+				Box::new(Expression::Not(Box::new(Expression::Leaf(pair[0].clone())))),
+				Box::new(Expression::Leaf(pair[1].clone())),
+			)),
+		)
+	}))
+	.unwrap();
+
+	let optimized_expression = OptimizedExpression::new(&expression);
+	let assignments = collect_satisfying_assignments(&optimized_expression);
+	assert_eq!(2, assignments.len());
+	for assignment in assignments {
+		assert!(optimized_expression.satisfies(|variable_index| assignment[variable_index]));
+	}
+}
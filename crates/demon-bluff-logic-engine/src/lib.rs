@@ -2,19 +2,29 @@
 	breakpoint,
 	cold_path,
 	rust_cold_cc,
-	hash_set_entry,
 	gen_blocks,
 	maybe_uninit_slice,
 	maybe_uninit_fill
 )]
 
+mod board_layout_cnf;
 mod build_board_layouts;
 mod build_expression_for_villager_set;
+mod cnf;
+mod evaluation;
+mod explanation;
 mod expression_assertion;
+mod night_kill;
 mod optimized_expression;
+mod planner;
 mod player_action;
 mod prediction_error;
+mod probabilities;
 mod reveal_strategy;
+mod sat_backend;
+mod sat_solver;
+mod simulator;
+mod unsat_core;
 mod with_theoretical_testimony;
 
 use core::panic;
@@ -31,29 +41,49 @@ use std::{
 	usize,
 };
 
-use build_board_layouts::{BoardLayout, TheoreticalVillager, build_board_layouts};
-use build_expression_for_villager_set::{IndexTestimony, build_expression_for_villager_set};
+use build_expression_for_villager_set::build_expression_for_villager_set;
 use demon_bluff_gameplay_engine::{
 	Expression,
 	affect::Affect,
 	game_state::GameState,
-	testimony::{ArchitectClaim, BishopEvil, ConfessorClaim, Direction, Testimony, index_offset},
-	villager::{Demon, GoodVillager, Minion, Outcast, Villager, VillagerArchetype, VillagerIndex},
+	rule_set::{JesterRule, JudgeRule},
+	testimony::{
+		ArchitectClaim, BishopEvil, ConfessorClaim, Direction, ScoutClaim, Testimony, index_offset,
+	},
+	villager::{
+		Demon, ExecutionResult, GoodVillager, Minion, Outcast, Villager, VillagerArchetype,
+		VillagerIndex,
+	},
 };
 use expression_assertion::collect_satisfying_assignments;
 use itertools::Itertools;
 use log::{Level, Log, debug, info, log_enabled, trace, warn};
+use night_kill::predict_night_kill;
 use optimized_expression::OptimizedExpression;
 use rayon::prelude::{
 	IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
 };
 use tracy_client::{frame_mark, span};
+use unsat_core::unsat_core;
 use with_theoretical_testimony::{LayoutWithTestimonyAssigments, with_theoretical_testimony};
 
 pub use self::{
+	board_layout_cnf::BoardLayoutCnf,
+	build_board_layouts::{BoardLayout, TheoreticalVillager, build_board_layouts},
+	build_expression_for_villager_set::IndexTestimony,
+	cnf::Cnf,
+	evaluation::{DeckConfiguration, EvaluationReport, StrategyEvaluation, evaluate_strategies},
+	explanation::{
+		EliminatedLayout, Explanation, SurvivingLayout, explain, predict_with_explanation,
+	},
+	planner::{RankedAction, rank_actions},
 	player_action::{AbilityAttempt, PlayerAction},
 	prediction_error::PredictionError,
+	probabilities::{VillagerProbabilities, villager_probabilities},
 	reveal_strategy::RevealStrategy,
+	sat_backend::{BuiltinSatBackend, ExternalSatBackend, SatBackend, SatBackendError},
+	simulator::{Simulation, SimulationError, SimulationOutcome, SimulationRecord},
+	unsat_core::UnsatCore,
 };
 
 struct PredictionResult {
@@ -68,6 +98,10 @@ pub fn predict(
 	reveal_strategy: RevealStrategy,
 ) -> Result<HashSet<PlayerAction>, PredictionError> {
 	let zone = span!("Predict");
+	if state.must_take_night_action() {
+		return predict_night_kill(log, state);
+	}
+
 	let mut any_revealed = false;
 	state.iter_villagers(|_, villager| {
 		if let Villager::Hidden(_) = villager {
@@ -118,7 +152,7 @@ pub fn predict(
 	});
 
 	match revealable_index {
-		Some(_) => Ok(reveal_strategy.get_reveal(log, state)),
+		Some(_) => reveal_strategy.get_reveal(log, state, need_more_info_result.as_deref()),
 		None => {
 			let mut remaining_unused_abilities = 0;
 			state.iter_villagers(|_, villager| {
@@ -477,11 +511,22 @@ fn predict_core(
 					}
 
 					if let Some((most_common_indicies, _)) = valid_prediction.most_common_indicies {
+						// every day spent here brings Lilis's night closer, get her first if she's a choice
+						let most_common_indicies = if state.night_actions_in_play() {
+							prefer_night_effect_executions(
+								log,
+								state,
+								&valid_prediction.all_matching_layouts,
+								most_common_indicies,
+							)
+						} else {
+							most_common_indicies
+						};
+
 						let mut actions: HashSet<PlayerAction> =
 							HashSet::with_capacity(most_common_indicies.len());
 						// select the most common indicies
 						for index in most_common_indicies {
-							// TODO: does it make sense to kill ASAP if there's a night affect in play?
 							actions.insert(PlayerAction::TryExecute(index));
 						}
 
@@ -551,7 +596,11 @@ fn predict_board_configs(
 	)> = configs.into_iter().collect();
 
 	if potential_board_configurations.is_empty() {
-		return Err(PredictionError::GameUnsolvable);
+		return Err(PredictionError::GameUnsolvable(unsat_core(
+			log,
+			game_state,
+			[],
+		)));
 	}
 
 	// Step two run possibilities, if only one satisfies, execute evils in board layout, if more than one satisfies and at least one evil overlaps on all, execute that one, otherwise, gather more info
@@ -637,7 +686,13 @@ fn predict_board_configs(
 			};
 
 		if master_expression_satisfying_assignments.is_empty() {
-			return Err(PredictionError::GameUnsolvable);
+			return Err(PredictionError::GameUnsolvable(unsat_core(
+				log,
+				game_state,
+				potential_board_configurations
+					.iter()
+					.map(|(board_config, _)| board_config),
+			)));
 		}
 
 		info!(
@@ -726,6 +781,17 @@ fn predict_board_configs(
 			}
 		}
 
+		if non_hypothetical_pass && all_matching_layouts.is_empty() {
+			warn!(logger: log, "Every board configuration was contradicted by the testimonies");
+			return Err(PredictionError::GameUnsolvable(unsat_core(
+				log,
+				game_state,
+				potential_board_configurations
+					.iter()
+					.map(|(board_config, _)| board_config),
+			)));
+		}
+
 		if log_enabled!(logger: log, Level::Info) {
 			let mut evil_layout_count = 0;
 			let mut layout_count = 0;
@@ -887,6 +953,14 @@ fn predict_board_configs(
 			}
 
 			if non_hypothetical_pass {
+				most_common_evil_index_occurrences = filter_risky_executions(
+					log,
+					game_state,
+					&all_matching_layouts,
+					&evil_index_occurrences_in_matching_layouts,
+					most_common_evil_index_occurrences,
+				);
+
 				// always perfer to kill hidden villagers if necessary to get more info
 				let mut has_hiddens = false;
 				let mut has_knights = false;
@@ -924,6 +998,12 @@ fn predict_board_configs(
 						}
 					});
 				}
+
+				most_common_evil_index_occurrences = prefer_cheapest_executions(
+					game_state,
+					&all_matching_layouts,
+					most_common_evil_index_occurrences,
+				);
 			}
 
 			return Ok(PredictionResult3::PredictionResult(PredictionResult {
@@ -952,12 +1032,7 @@ fn applicator<'a>(
 	assignments_to_iterate: impl ParallelIterator<Item = (usize, ExpandedAssignmentsType<'a>)>,
 ) -> Vec<(usize, HashMap<IndexTestimony, bool>)> {
 	let zone = span!("Work Zone");
-	let wretch_in_play = game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Wretch));
-	let drunk_in_play = game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Drunk));
-	let knight_in_play =
-		game_state.role_in_play(VillagerArchetype::GoodVillager(GoodVillager::Knight));
-	let bombardier_in_play =
-		game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Bombardier));
+	let roles_in_play = RolesInPlay::new(game_state);
 	assignments_to_iterate
 		.filter_map(|(board_index, assignment_type)| {
 			let mapped_assignment;
@@ -990,10 +1065,7 @@ fn applicator<'a>(
 					board_expression.variables(),
 					layout,
 					game_state,
-					wretch_in_play,
-					drunk_in_play,
-					knight_in_play,
-					bombardier_in_play,
+					roles_in_play,
 				) {
 				matching_configs.fetch_add(1, Ordering::Relaxed);
 
@@ -1020,23 +1092,184 @@ fn applicator<'a>(
 		.collect()
 }
 
+struct ExecutionRisk {
+	loss_chance: f64,
+	expected_deduction: f64,
+}
+
+fn execution_risk<'a>(
+	layouts: impl Iterator<Item = &'a BoardLayout>,
+	index: &VillagerIndex,
+	game_state: &GameState,
+) -> ExecutionRisk {
+	let hitpoints = game_state.hitpoints();
+	let mut total_layouts = 0;
+	let mut losing_layouts = 0;
+	let mut total_deduction = 0;
+	for layout in layouts {
+		total_layouts += 1;
+		match game_state
+			.rule_set()
+			.execution_result(&layout.villagers[index.0].inner)
+		{
+			ExecutionResult::EvilKilled => {}
+			ExecutionResult::SelfDestructKilled => losing_layouts += 1,
+			ExecutionResult::HealthDeduction(deduction) => {
+				if deduction >= hitpoints {
+					losing_layouts += 1;
+				}
+
+				total_deduction += deduction as usize;
+			}
+		}
+	}
+
+	if total_layouts == 0 {
+		return ExecutionRisk {
+			loss_chance: 0.0,
+			expected_deduction: 0.0,
+		};
+	}
+
+	ExecutionRisk {
+		loss_chance: losing_layouts as f64 / total_layouts as f64,
+		expected_deduction: total_deduction as f64 / total_layouts as f64,
+	}
+}
+
+/// Narrows down a guess to the executions that can't lose us the game
+fn filter_risky_executions(
+	log: &impl Log,
+	game_state: &GameState,
+	all_matching_layouts: &HashMap<BoardLayout, Vec<HashMap<IndexTestimony, bool>>>,
+	evil_index_occurrences: &HashMap<VillagerIndex, usize>,
+	mut candidates: Vec<VillagerIndex>,
+) -> Vec<VillagerIndex> {
+	let risks: HashMap<&VillagerIndex, ExecutionRisk> = evil_index_occurrences
+		.keys()
+		.map(|index| {
+			(
+				index,
+				execution_risk(all_matching_layouts.keys(), index, game_state),
+			)
+		})
+		.collect();
+
+	let lowest_loss_chance = candidates
+		.iter()
+		.map(|index| risks[index].loss_chance)
+		.fold(f64::INFINITY, f64::min);
+	if lowest_loss_chance > 0.0 {
+		// every likely evil could end the game, settle for a less likely one that can't
+		let mut safe_alternatives: Vec<VillagerIndex> = risks
+			.iter()
+			.filter(|(index, risk)| {
+				risk.loss_chance == 0.0
+					&& match game_state.villager(index) {
+						Villager::Active(active_villager) => !active_villager.cant_kill(),
+						Villager::Hidden(hidden_villager) => {
+							!hidden_villager.cant_kill() && !hidden_villager.dead()
+						}
+						Villager::Confirmed(_) => false,
+					}
+			})
+			.map(|(index, _)| (*index).clone())
+			.collect();
+
+		if safe_alternatives.is_empty() {
+			warn!(logger: log, "Every guess has at least a {:.2} chance of losing the game with {} hitpoints remaining", lowest_loss_chance, game_state.hitpoints());
+			candidates.retain(|index| risks[index].loss_chance <= lowest_loss_chance);
+		} else {
+			let highest_occurrences = safe_alternatives
+				.iter()
+				.map(|index| evil_index_occurrences[index])
+				.max()
+				.expect("Safe alternatives can't be empty here");
+			safe_alternatives.retain(|index| evil_index_occurrences[index] == highest_occurrences);
+
+			info!(logger: log, "The most likely evils could lose us the game with {} hitpoints remaining. Guessing less likely evils that can't", game_state.hitpoints());
+			candidates = safe_alternatives;
+		}
+	}
+
+	candidates
+}
+
+/// Of otherwise equal guesses, keep the ones that cost the least health when wrong
+fn prefer_cheapest_executions(
+	game_state: &GameState,
+	all_matching_layouts: &HashMap<BoardLayout, Vec<HashMap<IndexTestimony, bool>>>,
+	mut candidates: Vec<VillagerIndex>,
+) -> Vec<VillagerIndex> {
+	let expected_deductions: HashMap<VillagerIndex, f64> = candidates
+		.iter()
+		.map(|index| {
+			(
+				index.clone(),
+				execution_risk(all_matching_layouts.keys(), index, game_state).expected_deduction,
+			)
+		})
+		.collect();
+
+	let lowest_expected_deduction = expected_deductions
+		.values()
+		.copied()
+		.fold(f64::INFINITY, f64::min);
+	candidates
+		.retain(|index| expected_deductions[index] <= lowest_expected_deduction + f64::EPSILON);
+
+	candidates
+}
+
+fn has_night_effect(layout: &BoardLayout, index: &VillagerIndex, total_villagers: usize) -> bool {
+	layout.evil_locations.contains(index)
+		&& matches!(
+			layout.villagers[index.0]
+				.inner
+				.true_identity()
+				.affect(total_villagers, Some(index.clone())),
+			Some(Affect::Night(_))
+		)
+}
+
+fn prefer_night_effect_executions(
+	log: &impl Log,
+	game_state: &GameState,
+	all_matching_layouts: &HashMap<BoardLayout, Vec<HashMap<IndexTestimony, bool>>>,
+	candidates: Vec<VillagerIndex>,
+) -> Vec<VillagerIndex> {
+	let night_effect_candidates: Vec<VillagerIndex> = candidates
+		.iter()
+		.filter(|index| {
+			all_matching_layouts
+				.keys()
+				.any(|layout| has_night_effect(layout, index, game_state.total_villagers()))
+		})
+		.cloned()
+		.collect();
+
+	if night_effect_candidates.is_empty() || night_effect_candidates.len() == candidates.len() {
+		candidates
+	} else {
+		info!(logger: log, "Night effects are in play. Executing the choices that could be causing them first");
+		night_effect_candidates
+	}
+}
+
 fn kill_board_configs(
 	board_configs: impl Iterator<Item = BoardLayout>,
 	state: &GameState,
 ) -> HashSet<PlayerAction> {
 	let mut kills = HashSet::new();
+	let mut night_effect_occurrences: HashMap<VillagerIndex, usize> = HashMap::new();
 
-	// TODO: Find most common night effect positions
 	for board_config in board_configs {
 		// minor thing, important to kill evils with night effects first
 		for evil_index in &board_config.evil_locations {
-			if let Some(Affect::Night(_)) = board_config.villagers[evil_index.0]
-				.inner
-				.true_identity()
-				.affect(state.total_villagers(), Some(evil_index.clone()))
-			{
-				kills.insert(PlayerAction::TryExecute(evil_index.clone()));
-				return kills;
+			if has_night_effect(&board_config, evil_index, state.total_villagers()) {
+				*night_effect_occurrences
+					.entry(evil_index.clone())
+					.or_default() += 1;
 			}
 		}
 
@@ -1045,21 +1278,73 @@ fn kill_board_configs(
 		}
 	}
 
+	// the most common night effect positions
+	if let Some(highest_count) = night_effect_occurrences.values().max().copied() {
+		return night_effect_occurrences
+			.into_iter()
+			.filter(|(_, count)| *count == highest_count)
+			.map(|(index, _)| PlayerAction::TryExecute(index))
+			.collect();
+	}
+
 	kills
 }
 
+/// The roles whose presence changes what a testimony can be checked against
+#[derive(Debug, Clone, Copy)]
+struct RolesInPlay {
+	wretch: bool,
+	drunk: bool,
+	knight: bool,
+	bombardier: bool,
+}
+
+impl RolesInPlay {
+	fn new(game_state: &GameState) -> Self {
+		Self {
+			wretch: game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Wretch)),
+			drunk: game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Drunk)),
+			knight: game_state.role_in_play(VillagerArchetype::GoodVillager(GoodVillager::Knight)),
+			bombardier: game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Bombardier)),
+		}
+	}
+}
+
 fn validate_assignment(
 	log: &impl Log,
 	assignment: &Vec<bool>,
 	variables: &[IndexTestimony],
 	board_config: &BoardLayout,
 	game_state: &GameState,
-	wretch_in_play: bool,
-	drunk_in_play: bool,
-	knight_in_play: bool,
-	bombardier_in_play: bool,
+	roles_in_play: RolesInPlay,
 ) -> bool {
+	first_invalid_testimony(
+		log,
+		assignment,
+		variables,
+		board_config,
+		game_state,
+		roles_in_play,
+	)
+	.is_none()
+}
+
+/// The index of the first variable whose assigned truthfulness the board config contradicts
+fn first_invalid_testimony(
+	log: &impl Log,
+	assignment: &Vec<bool>,
+	variables: &[IndexTestimony],
+	board_config: &BoardLayout,
+	game_state: &GameState,
+	roles_in_play: RolesInPlay,
+) -> Option<usize> {
 	debug_assert_eq!(variables.len(), assignment.len());
+	let RolesInPlay {
+		wretch: wretch_in_play,
+		drunk: drunk_in_play,
+		knight: knight_in_play,
+		bombardier: bombardier_in_play,
+	} = roles_in_play;
 
 	let mut interested = false;
 	if false
@@ -1103,20 +1388,30 @@ fn validate_assignment(
 			}
 			Testimony::Evil(villager_index) => {
 				let theoretical = &theoreticals[villager_index.0];
-				if_unknown_good_use_truthful(
-					theoretical,
-					theoretical.inner.true_identity().appears_evil(),
-					wretch_in_play,
-				)
+				if *testifier.inner.instance().archetype()
+					== VillagerArchetype::GoodVillager(GoodVillager::Jester)
+					&& game_state.rule_set().jester == JesterRule::IsEvil
+				{
+					theoretical.inner.true_identity().is_evil()
+				} else {
+					if_unknown_good_use_truthful(
+						theoretical,
+						theoretical.inner.true_identity().appears_evil(),
+						wretch_in_play,
+					)
+				}
 			}
 			Testimony::Corrupt(villager_index) => theoreticals[villager_index.0].inner.corrupted(),
 			Testimony::Lying(villager_index) => {
 				let theoretical = &theoreticals[villager_index.0];
-				if_unknown_good_use_truthful(
-					theoretical,
-					theoretical.inner.will_lie(),
-					drunk_in_play,
-				)
+				let lying = match game_state.rule_set().judge {
+					JudgeRule::WillLie => theoretical.inner.will_lie(),
+					JudgeRule::LyingRole => {
+						!theoretical.inner.instance().archetype().cannot_lie()
+							&& theoretical.inner.true_identity().lies()
+					}
+				};
+				if_unknown_good_use_truthful(theoretical, lying, drunk_in_play)
 			}
 			Testimony::Cured(amount) => {
 				let indicies_to_check = [
@@ -1247,57 +1542,27 @@ fn validate_assignment(
 				}
 			}
 			Testimony::Scout(scout_claim) => {
-				let mut iterator = theoreticals.iter().enumerate().filter(|(_, theoretical)| {
-					theoretical.inner.true_identity() == scout_claim.evil_role()
-				});
-				let likely_talking_about = iterator.next();
-
-				match likely_talking_about {
-					Some((target_index, _)) => {
-						if iterator.next().is_some() {
-							todo!("Handle multiple matches in scout claim!");
-						}
-
-						let target_index = VillagerIndex(target_index);
-
-						let matches;
-						let mut i = 0;
-						loop {
-							i += 1;
-							let clockwise_read =
-								index_offset(&target_index, game_state.total_villagers(), i, true);
-							let counterclockwise_read =
-								index_offset(&target_index, game_state.total_villagers(), i, false);
-
-							let clockwise_theoretical = &theoreticals[clockwise_read.0];
-							let counterclockwise_theoretical =
-								&theoreticals[counterclockwise_read.0];
-
-							let unknown_good_villager_appears_evil =
-								(i == scout_claim.distance()) == *truthful;
-							let clockwise_appears_evil =
-								if clockwise_theoretical.unknown_unrevealed_good() {
-									unknown_good_villager_appears_evil
-								} else {
-									clockwise_theoretical.inner.true_identity().appears_evil()
-								};
-							let counterclockwise_appears_evil =
-								if counterclockwise_theoretical.unknown_unrevealed_good() {
-									unknown_good_villager_appears_evil
-								} else {
-									counterclockwise_theoretical
-										.inner
-										.true_identity()
-										.appears_evil()
-								};
-							if clockwise_appears_evil || counterclockwise_appears_evil {
-								matches = i == scout_claim.distance();
-								break;
-							}
-						}
+				let total_villagers = game_state.total_villagers();
+				let targets: Vec<VillagerIndex> = theoreticals
+					.iter()
+					.enumerate()
+					.filter(|(_, theoretical)| {
+						theoretical.inner.true_identity() == scout_claim.evil_role()
+					})
+					.map(|(target_index, _)| VillagerIndex(target_index))
+					.collect();
 
-						matches
-					}
+				// with duplicated roles the scout is talking about the one nearest to them, or any of them on a tie
+				let scout_distance = |target_index: &VillagerIndex| {
+					circular_distance(&index_testimony.index, target_index, total_villagers)
+				};
+				match targets.iter().map(scout_distance).min() {
+					Some(nearest_distance) => targets
+						.iter()
+						.filter(|target_index| scout_distance(target_index) == nearest_distance)
+						.any(|target_index| {
+							scout_claim_matches(theoreticals, target_index, scout_claim, *truthful)
+						}),
 					None => false,
 				}
 			}
@@ -1587,7 +1852,7 @@ fn validate_assignment(
 			} else {
 				trace!(logger: log, "Validation failed ({}: {}|FULL: {}): {}", if *truthful { "TRUE" } else { "FALSE" }, index_testimony, full_testimony, board_config.description);
 			}
-			return false;
+			return Some(variable_index);
 		}
 	}
 
@@ -1597,7 +1862,49 @@ fn validate_assignment(
 		trace!(logger: log, "Validation passed: {}", board_config.description);
 	}
 
-	true
+	None
+}
+
+fn circular_distance(from: &VillagerIndex, to: &VillagerIndex, total_villagers: usize) -> usize {
+	let clockwise_distance = (to.0 + total_villagers - from.0) % total_villagers;
+	clockwise_distance.min(total_villagers - clockwise_distance)
+}
+
+/// Whether the closest evil to the target is at the distance the scout claims
+fn scout_claim_matches(
+	theoreticals: &[TheoreticalVillager],
+	target_index: &VillagerIndex,
+	scout_claim: &ScoutClaim,
+	truthful: bool,
+) -> bool {
+	let mut i = 0;
+	loop {
+		i += 1;
+		let clockwise_read = index_offset(target_index, theoreticals.len(), i, true);
+		let counterclockwise_read = index_offset(target_index, theoreticals.len(), i, false);
+
+		let clockwise_theoretical = &theoreticals[clockwise_read.0];
+		let counterclockwise_theoretical = &theoreticals[counterclockwise_read.0];
+
+		let unknown_good_villager_appears_evil = (i == scout_claim.distance()) == truthful;
+		let clockwise_appears_evil = if clockwise_theoretical.unknown_unrevealed_good() {
+			unknown_good_villager_appears_evil
+		} else {
+			clockwise_theoretical.inner.true_identity().appears_evil()
+		};
+		let counterclockwise_appears_evil =
+			if counterclockwise_theoretical.unknown_unrevealed_good() {
+				unknown_good_villager_appears_evil
+			} else {
+				counterclockwise_theoretical
+					.inner
+					.true_identity()
+					.appears_evil()
+			};
+		if clockwise_appears_evil || counterclockwise_appears_evil {
+			return i == scout_claim.distance();
+		}
+	}
 }
 
 fn assignment_applies(theoretical: &TheoreticalVillager, testimony: &Testimony) -> bool {
@@ -1629,3 +1936,125 @@ fn testimony_exists_in_expression(
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use demon_bluff_gameplay_engine::{game_state::DrawStats, villager::HiddenVillager};
+
+	use super::*;
+
+	// the Minion is #1 in two of the layouts and #2 in the other
+	fn risk_test_layouts(
+		index_1_identity: VillagerArchetype,
+		index_0_good_identity: VillagerArchetype,
+	) -> HashMap<BoardLayout, Vec<HashMap<IndexTestimony, bool>>> {
+		let layout = |evil_index: usize, description: &str| BoardLayout {
+			description: description.to_string(),
+			..BoardLayout::revealed((0..7).map(|index| {
+				let true_identity = if index == evil_index {
+					Some(VillagerArchetype::Minion(Minion::Minion))
+				} else if index == 0 {
+					Some(index_0_good_identity.clone())
+				} else if index == 1 {
+					Some(index_1_identity.clone())
+				} else {
+					None
+				};
+				(
+					VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+					true_identity,
+					false,
+				)
+			}))
+		};
+
+		let mut layouts = HashMap::new();
+		layouts.insert(layout(0, "a"), Vec::new());
+		layouts.insert(layout(0, "b"), Vec::new());
+		layouts.insert(layout(1, "c"), Vec::new());
+		layouts
+	}
+
+	fn risk_test_state(hitpoints: u8) -> GameState {
+		GameState::new(
+			None,
+			DrawStats::new(6, 0, 1, 0),
+			vec![
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				VillagerArchetype::Outcast(Outcast::Drunk),
+				VillagerArchetype::Outcast(Outcast::Bombardier),
+				VillagerArchetype::Minion(Minion::Minion),
+			],
+			(0..7)
+				.map(|_| Villager::Hidden(HiddenVillager::new(false, false, false)))
+				.collect(),
+			Vec::new(),
+			hitpoints,
+			1,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_risky_executions() {
+		let log = log::logger();
+		let mut evil_index_occurrences = HashMap::new();
+		evil_index_occurrences.insert(VillagerIndex(0), 2);
+		evil_index_occurrences.insert(VillagerIndex(1), 1);
+
+		let layouts = risk_test_layouts(
+			VillagerArchetype::Outcast(Outcast::Drunk),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		);
+
+		// plenty of health, go with the likeliest evil
+		assert_eq!(
+			vec![VillagerIndex(0)],
+			filter_risky_executions(
+				&log,
+				&risk_test_state(10),
+				&layouts,
+				&evil_index_occurrences,
+				vec![VillagerIndex(0)],
+			)
+		);
+
+		// with 5 health a wrong guess on VillagerIndex(0), a Confessor when good, would kill us. A wrong guess on the likely drunk at VillagerIndex(1) wouldn't
+		assert_eq!(
+			vec![VillagerIndex(1)],
+			filter_risky_executions(
+				&log,
+				&risk_test_state(5),
+				&layouts,
+				&evil_index_occurrences,
+				vec![VillagerIndex(0)],
+			)
+		);
+
+		// cheaper guesses win ties
+		assert_eq!(
+			vec![VillagerIndex(1)],
+			prefer_cheapest_executions(
+				&risk_test_state(10),
+				&layouts,
+				vec![VillagerIndex(0), VillagerIndex(1)],
+			)
+		);
+
+		// the bombardier always loses the game
+		let layouts = risk_test_layouts(
+			VillagerArchetype::Outcast(Outcast::Drunk),
+			VillagerArchetype::Outcast(Outcast::Bombardier),
+		);
+		assert_eq!(
+			vec![VillagerIndex(1)],
+			filter_risky_executions(
+				&log,
+				&risk_test_state(10),
+				&layouts,
+				&evil_index_occurrences,
+				vec![VillagerIndex(0)],
+			)
+		);
+	}
+}
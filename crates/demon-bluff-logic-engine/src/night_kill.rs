@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use demon_bluff_gameplay_engine::{
+	game_state::GameState,
+	villager::{Demon, Villager, VillagerArchetype, VillagerIndex},
+};
+use log::{Log, info};
+use tracy_client::span;
+
+use crate::{PlayerAction, PredictionError, probabilities::villager_probabilities};
+
+/// Picks who Lilis should kill once [`GameState::must_take_night_action`].
+/// A hidden evil Lilis kills can never be executed, so the villagers most likely to be good are the ones to give up
+pub fn predict_night_kill(
+	log: &impl Log,
+	state: &GameState,
+) -> Result<HashSet<PlayerAction>, PredictionError> {
+	let zone = span!("Predict Night Kill");
+	let lilis = VillagerArchetype::Demon(Demon::Lilis);
+	let lilis_executed = state.villagers().iter().any(|villager| {
+		if let Villager::Confirmed(confirmed_villager) = villager {
+			*confirmed_villager.true_identity() == lilis
+		} else {
+			false
+		}
+	});
+
+	let mut nobody = HashSet::with_capacity(1);
+	nobody.insert(PlayerAction::LilisNightKill(None));
+	if lilis_executed || !state.role_in_play(lilis) {
+		info!(logger: log, "It's night but Lilis can't be alive, nobody dies");
+		return Ok(nobody);
+	}
+
+	let targets: Vec<VillagerIndex> = state
+		.villager_indicies()
+		.filter(|index| match state.villager(index) {
+			Villager::Hidden(hidden_villager) => {
+				!hidden_villager.dead() && !hidden_villager.cant_kill()
+			}
+			Villager::Active(_) | Villager::Confirmed(_) => false,
+		})
+		.collect();
+	if targets.is_empty() {
+		info!(logger: log, "It's night but there are no hidden villagers Lilis can kill");
+		return Ok(nobody);
+	}
+
+	let probabilities = villager_probabilities(log, state)?;
+	let lowest_evil_probability = targets
+		.iter()
+		.map(|index| probabilities[index].evil)
+		.fold(f64::INFINITY, f64::min);
+
+	info!(logger: log, "It's night. Giving Lilis the hidden villagers with a {} chance of being evil", lowest_evil_probability);
+	Ok(targets
+		.into_iter()
+		.filter(|index| probabilities[index].evil <= lowest_evil_probability + f64::EPSILON)
+		.map(|index| PlayerAction::LilisNightKill(Some(index)))
+		.collect())
+}
@@ -8,6 +8,8 @@ use std::{
 use demon_bluff_gameplay_engine::Expression;
 use tracy_client::span;
 
+use crate::cnf::Cnf;
+
 pub struct OptimizedExpression<'a, T>
 where
 	T: Eq + Clone,
@@ -225,6 +227,58 @@ where
 	pub fn variables(&self) -> &[T] {
 		&self.variables
 	}
+
+	/// Tseitin transformation, every non-leaf clause gets an auxiliary variable so the result stays linear in size
+	pub fn to_cnf(&self) -> Cnf {
+		let zone = span!("CNF Conversion");
+		let mut cnf = Cnf::new(self.variables.len());
+		let root = self.clause_to_cnf(0, &mut cnf);
+		cnf.add_clause(vec![root]);
+		cnf
+	}
+
+	fn clause_to_cnf(&self, clause_index: usize, cnf: &mut Cnf) -> i32 {
+		let next_clause_index = clause_index + 1;
+		match &self.clauses[clause_index] {
+			ExpressionClause::Variable(variable_index) => Cnf::literal(*variable_index),
+			ExpressionClause::Not => -self.clause_to_cnf(next_clause_index, cnf),
+			ExpressionClause::And(rhs) => {
+				let lhs = self.clause_to_cnf(next_clause_index, cnf);
+				let rhs = self.clause_to_cnf(*rhs, cnf);
+				let and = cnf.new_auxiliary_variable();
+				cnf.add_clause(vec![-and, lhs]);
+				cnf.add_clause(vec![-and, rhs]);
+				cnf.add_clause(vec![and, -lhs, -rhs]);
+				and
+			}
+			ExpressionClause::Or(rhs) => {
+				let lhs = self.clause_to_cnf(next_clause_index, cnf);
+				let rhs = self.clause_to_cnf(*rhs, cnf);
+				let or = cnf.new_auxiliary_variable();
+				cnf.add_clause(vec![-or, lhs, rhs]);
+				cnf.add_clause(vec![or, -lhs]);
+				cnf.add_clause(vec![or, -rhs]);
+				or
+			}
+			ExpressionClause::MajorOr(clause_indicies) => {
+				let mut operands = Vec::with_capacity(clause_indicies.len() + 1);
+				operands.push(self.clause_to_cnf(next_clause_index, cnf));
+				for clause_index in clause_indicies {
+					operands.push(self.clause_to_cnf(*clause_index, cnf));
+				}
+
+				let or = cnf.new_auxiliary_variable();
+				let mut definition = Vec::with_capacity(operands.len() + 1);
+				definition.push(-or);
+				for operand in operands {
+					definition.push(operand);
+					cnf.add_clause(vec![or, -operand]);
+				}
+				cnf.add_clause(definition);
+				or
+			}
+		}
+	}
 }
 
 unsafe impl<'a, T> Send for OptimizedExpression<'a, T> where T: Eq + Clone {}
@@ -0,0 +1,403 @@
+use std::collections::{BTreeSet, HashMap, HashSet, hash_map::Entry};
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::GameState,
+	testimony::Testimony,
+	villager::{Villager, VillagerArchetype, VillagerIndex},
+};
+use log::{Log, debug, info};
+use serde::Serialize;
+use tracy_client::span;
+
+use crate::{
+	PlayerAction, PredictionError, PredictionResult2,
+	build_board_layouts::{BoardLayout, TheoreticalVillager, build_board_layouts},
+	execution_risk, predict_core,
+	with_theoretical_testimony::{
+		LayoutWithTestimonyAssigments, with_theoretical_testimony_for_villager,
+	},
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedAction {
+	pub action: PlayerAction,
+	/// Expected reduction in entropy (bits) over the surviving evil location sets
+	pub expected_information_gain: f64,
+	/// Hitpoints an execution is expected to cost
+	pub expected_hitpoint_loss: f64,
+	/// Information gain less the bits the execution risks. Actions are ranked by this
+	pub score: f64,
+}
+
+/// Scores every legal [`PlayerAction`] by the expected entropy reduction over the surviving evil location sets. Best first
+pub fn rank_actions(
+	log: &impl Log,
+	state: &GameState,
+) -> Result<Vec<RankedAction>, PredictionError> {
+	let layouts = match predict_core(
+		log,
+		state,
+		build_board_layouts(state)
+			.into_iter()
+			.map(|board_layout| (board_layout, None)),
+		false,
+	) {
+		PredictionResult2::KillResult(result) => {
+			// nothing left to learn, the kills are already decided
+			let mut kills: Vec<PlayerAction> = result?.into_iter().collect();
+			kills.sort_by_key(|action| format!("{}", action));
+			return Ok(kills
+				.into_iter()
+				.map(|action| RankedAction {
+					action,
+					expected_information_gain: 0.0,
+					expected_hitpoint_loss: 0.0,
+					score: 0.0,
+				})
+				.collect());
+		}
+		PredictionResult2::NeedMoreInfoResult(layouts) => layouts,
+		PredictionResult2::ConfigCountsAfterAbility(_) => {
+			unreachable!("Incorrect return type!")
+		}
+	};
+
+	Ok(rank_layouts(log, state, &layouts))
+}
+
+/// Ranks the actions over layouts [`predict_core`] couldn't decide kills for
+fn rank_layouts(
+	log: &impl Log,
+	state: &GameState,
+	layouts: &[LayoutWithTestimonyAssigments],
+) -> Vec<RankedAction> {
+	let zone = span!("Rank Actions");
+
+	let current_entropy = evil_locations_entropy(layouts);
+
+	info!(logger: log, "Ranking actions over {} layouts with {:.3} bits of entropy", layouts.len(), current_entropy);
+
+	let mut ranked_actions = Vec::new();
+	state.iter_villagers(|index, villager| {
+		match villager {
+			Villager::Hidden(hidden_villager) => {
+				if !hidden_villager.cant_reveal() {
+					let expected_information_gain = current_entropy
+						- partition_entropy(layouts, |layout| {
+							reveal_outcome(&layout.villagers[index.0])
+						});
+					ranked_actions.push(RankedAction {
+						action: PlayerAction::TryReveal(index.clone()),
+						expected_information_gain,
+						expected_hitpoint_loss: 0.0,
+						score: expected_information_gain,
+					});
+				}
+
+				if !hidden_villager.cant_kill() && !hidden_villager.dead() {
+					ranked_actions.push(rank_execution(state, layouts, &index, current_entropy));
+				}
+			}
+			Villager::Active(active_villager) => {
+				if !active_villager.cant_kill() {
+					ranked_actions.push(rank_execution(state, layouts, &index, current_entropy));
+				}
+
+				if active_villager.instance().testimony().is_none() {
+					ranked_actions.extend(rank_abilities(
+						log,
+						state,
+						layouts,
+						&index,
+						current_entropy,
+					));
+				}
+			}
+			Villager::Confirmed(confirmed_villager) => {
+				if confirmed_villager.instance().testimony().is_none() {
+					ranked_actions.extend(rank_abilities(
+						log,
+						state,
+						layouts,
+						&index,
+						current_entropy,
+					));
+				}
+			}
+		}
+		true
+	});
+
+	// stable so equal scores keep villager order
+	ranked_actions.sort_by(|lhs, rhs| rhs.score.total_cmp(&lhs.score));
+
+	for ranked_action in &ranked_actions {
+		debug!(logger: log, "{}: {:.3} bits, {:.3} hitpoints", ranked_action.action, ranked_action.expected_information_gain, ranked_action.expected_hitpoint_loss);
+	}
+
+	ranked_actions
+}
+
+/// The reveals and abilities tied for the best positive [`RankedAction::score`], empty if none is expected to tell us anything.
+/// Executions are left to the kill decisions, an unknown villager's reveal is worth more than the planner can score it against the hitpoints at risk
+pub(crate) fn best_actions(
+	log: &impl Log,
+	state: &GameState,
+	layouts: &[LayoutWithTestimonyAssigments],
+) -> HashSet<PlayerAction> {
+	let ranked_actions: Vec<RankedAction> = rank_layouts(log, state, layouts)
+		.into_iter()
+		.filter(|ranked_action| !matches!(ranked_action.action, PlayerAction::TryExecute(_)))
+		.collect();
+	let Some(best_score) = ranked_actions
+		.first()
+		.map(|ranked_action| ranked_action.score)
+		.filter(|score| *score > 0.0)
+	else {
+		return HashSet::new();
+	};
+
+	ranked_actions
+		.into_iter()
+		.take_while(|ranked_action| (best_score - ranked_action.score).abs() < f64::EPSILON)
+		.map(|ranked_action| ranked_action.action)
+		.collect()
+}
+
+/// Entropy (bits) over the evil location sets of the layouts
+fn evil_locations_entropy(layouts: &[LayoutWithTestimonyAssigments]) -> f64 {
+	entropy(
+		count_by_key(
+			layouts
+				.iter()
+				.map(|layout| layout.layout.evil_locations.clone()),
+		)
+		.into_values(),
+	)
+}
+
+fn rank_execution(
+	state: &GameState,
+	layouts: &[LayoutWithTestimonyAssigments],
+	index: &VillagerIndex,
+	current_entropy: f64,
+) -> RankedAction {
+	let expected_information_gain = current_entropy
+		- partition_entropy(layouts, |layout| {
+			execution_outcome(&layout.villagers[index.0])
+		});
+	let risk = execution_risk(layouts.iter().map(|layout| &layout.layout), index, state);
+
+	// losing the game, or every hitpoint, loses everything there was left to learn
+	let risked_fraction =
+		(risk.loss_chance + risk.expected_deduction / state.hitpoints() as f64).min(1.0);
+
+	RankedAction {
+		action: PlayerAction::TryExecute(index.clone()),
+		expected_information_gain,
+		expected_hitpoint_loss: risk.expected_deduction,
+		score: expected_information_gain - risked_fraction * current_entropy,
+	}
+}
+
+fn rank_abilities(
+	log: &impl Log,
+	state: &GameState,
+	layouts: &[LayoutWithTestimonyAssigments],
+	source: &VillagerIndex,
+	current_entropy: f64,
+) -> Vec<RankedAction> {
+	let zone = span!("Rank Abilities");
+
+	// layouts without any testimonies have no assignments, but we still need one to extend
+	let layouts: Vec<LayoutWithTestimonyAssigments> = layouts
+		.iter()
+		.cloned()
+		.map(|mut layout| {
+			if layout.satisfying_assignments.is_empty() {
+				layout.satisfying_assignments.push(HashMap::new());
+			}
+			layout
+		})
+		.collect();
+
+	let mut attempt_predictions: Vec<_> =
+		with_theoretical_testimony_for_villager(log, state, &layouts, source)
+			.attempt_predictions
+			.into_iter()
+			.collect();
+	attempt_predictions.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+	let mut ranked_abilities = Vec::with_capacity(attempt_predictions.len());
+	for (ability_attempt, mutations) in attempt_predictions {
+		// a source layout splits its weight between everything it could say, so it counts as much as it does in the current entropy
+		let mut outcome_weights: HashMap<BoardLayout, f64> = HashMap::new();
+		for mutation in &mutations {
+			let weight = 1.0 / mutation.potential_layouts.len() as f64;
+			for potential_layout in &mutation.potential_layouts {
+				*outcome_weights
+					.entry(potential_layout.layout.clone())
+					.or_default() += weight;
+			}
+		}
+
+		let PredictionResult2::ConfigCountsAfterAbility(layout_counts) = predict_core(
+			log,
+			state,
+			mutations
+				.into_iter()
+				.flat_map(|mutation| mutation.potential_layouts.into_iter())
+				.map(|potential_layout| {
+					(
+						potential_layout.layout,
+						Some(potential_layout.satisfying_assignments),
+					)
+				}),
+			true,
+		) else {
+			// every theoretical outcome contradicted itself
+			continue;
+		};
+
+		// group the surviving layouts by what the ability would tell us
+		let mut outcomes: HashMap<&Expression<Testimony>, Vec<(&BoardLayout, f64)>> =
+			HashMap::new();
+		for layout in layout_counts.iter().flat_map(|(layouts, _)| layouts.iter()) {
+			let generated_testimony = layout.villagers[source.0]
+				.inner
+				.instance()
+				.testimony()
+				.as_ref()
+				.expect("Ability usage didn't generate testimony?");
+			let weight = outcome_weights
+				.get(layout)
+				.copied()
+				.expect("Surviving layout wasn't a theoretical outcome?");
+			outcomes
+				.entry(generated_testimony)
+				.or_default()
+				.push((layout, weight));
+		}
+
+		// renormalise over the outcomes that didn't contradict themselves
+		let total_weight: f64 = outcomes
+			.values()
+			.flat_map(|outcome_layouts| outcome_layouts.iter().map(|(_, weight)| weight))
+			.sum();
+		if total_weight == 0.0 {
+			continue;
+		}
+
+		let expected_entropy: f64 = outcomes
+			.values()
+			.map(|outcome_layouts| {
+				let outcome_weight: f64 = outcome_layouts.iter().map(|(_, weight)| weight).sum();
+				outcome_weight / total_weight
+					* entropy(
+						weight_by_key(
+							outcome_layouts
+								.iter()
+								.map(|(layout, weight)| (layout.evil_locations.clone(), *weight)),
+						)
+						.into_values(),
+					)
+			})
+			.sum();
+
+		let expected_information_gain = current_entropy - expected_entropy;
+		ranked_abilities.push(RankedAction {
+			action: PlayerAction::Ability(ability_attempt),
+			expected_information_gain,
+			expected_hitpoint_loss: 0.0,
+			score: expected_information_gain,
+		});
+	}
+
+	ranked_abilities
+}
+
+/// What revealing the villager shows: its archetype and testimony, if the layout pins them down
+fn reveal_outcome(
+	theoretical: &TheoreticalVillager,
+) -> Option<(VillagerArchetype, Option<Expression<Testimony>>)> {
+	theoretical
+		.shown_archetype()
+		.map(|archetype| (archetype, theoretical.inner.instance().testimony().clone()))
+}
+
+/// What executing the villager tells us: the identity under a disguise and whether it was corrupted
+fn execution_outcome(theoretical: &TheoreticalVillager) -> (Option<VillagerArchetype>, bool) {
+	let true_identity = theoretical.inner.true_identity();
+	let disguised = theoretical.inner.instance().archetype() != true_identity;
+	(
+		disguised.then(|| true_identity.clone()),
+		theoretical.inner.corrupted(),
+	)
+}
+
+/// Expected entropy after learning which outcome the layout gives
+fn partition_entropy<Outcome>(
+	layouts: &[LayoutWithTestimonyAssigments],
+	outcome: impl Fn(&BoardLayout) -> Outcome,
+) -> f64
+where
+	Outcome: Eq + std::hash::Hash,
+{
+	let mut outcomes: HashMap<Outcome, Vec<BTreeSet<VillagerIndex>>> = HashMap::new();
+	for layout in layouts {
+		outcomes
+			.entry(outcome(&layout.layout))
+			.or_default()
+			.push(layout.layout.evil_locations.clone());
+	}
+
+	outcomes
+		.into_values()
+		.map(|evil_locations| {
+			let probability = evil_locations.len() as f64 / layouts.len() as f64;
+			probability * entropy(count_by_key(evil_locations.into_iter()).into_values())
+		})
+		.sum()
+}
+
+fn count_by_key<Key>(keys: impl Iterator<Item = Key>) -> HashMap<Key, f64>
+where
+	Key: Eq + std::hash::Hash,
+{
+	weight_by_key(keys.map(|key| (key, 1.0)))
+}
+
+fn weight_by_key<Key>(keyed_weights: impl Iterator<Item = (Key, f64)>) -> HashMap<Key, f64>
+where
+	Key: Eq + std::hash::Hash,
+{
+	let mut weights = HashMap::new();
+	for (key, weight) in keyed_weights {
+		match weights.entry(key) {
+			Entry::Occupied(mut occupied_entry) => *occupied_entry.get_mut() += weight,
+			Entry::Vacant(vacant_entry) => {
+				vacant_entry.insert(weight);
+			}
+		}
+	}
+
+	weights
+}
+
+fn entropy(weights: impl Iterator<Item = f64>) -> f64 {
+	let weights: Vec<f64> = weights.collect();
+	let total: f64 = weights.iter().sum();
+	if total == 0.0 {
+		return 0.0;
+	}
+
+	-weights
+		.into_iter()
+		.filter(|weight| *weight != 0.0)
+		.map(|weight| {
+			let probability = weight / total;
+			probability * probability.log2()
+		})
+		.sum::<f64>()
+}
@@ -1,19 +1,21 @@
 use std::{collections::BTreeSet, fmt::Display, hash::Hash};
 
 use demon_bluff_gameplay_engine::villager::VillagerIndex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Eq, Clone, Serialize, PartialOrd, Ord)]
+#[derive(Debug, Eq, Clone, Serialize, Deserialize, PartialOrd, Ord)]
 pub struct AbilityAttempt {
 	source: VillagerIndex,
 	targets: BTreeSet<VillagerIndex>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub enum PlayerAction {
 	TryReveal(VillagerIndex),
 	TryExecute(VillagerIndex),
 	Ability(AbilityAttempt),
+	/// Who Lilis should kill when the night comes, if anyone
+	LilisNightKill(Option<VillagerIndex>),
 }
 
 impl AbilityAttempt {
@@ -92,6 +94,10 @@ impl Display for PlayerAction {
 
 				Ok(())
 			}
+			Self::LilisNightKill(target) => match target {
+				Some(villager_index) => write!(f, "Lilis kills {villager_index}"),
+				None => write!(f, "Lilis kills nobody"),
+			},
 		}
 	}
 }
@@ -1,11 +1,15 @@
 use thiserror::Error;
 
+use crate::unsat_core::UnsatCore;
+
 #[derive(Error, Debug)]
 pub enum PredictionError {
 	#[error("Evaluation could not determine an action to perform!")]
 	ConclusiveNoAction,
 	#[error(
-		"The SAT solver could not find a solution to the game based on available permutations!"
+		"The SAT solver could not find a solution to the game based on available permutations! {0}"
 	)]
-	GameUnsolvable,
+	GameUnsolvable(UnsatCore),
+	#[error("There are no hidden villagers left to reveal")]
+	NothingToReveal,
 }
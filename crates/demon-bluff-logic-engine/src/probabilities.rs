@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+
+use demon_bluff_gameplay_engine::{
+	game_state::GameState,
+	villager::{ExecutionResult, VillagerArchetype, VillagerIndex},
+};
+use log::{Log, info};
+use serde::Serialize;
+use tracy_client::span;
+
+use crate::{
+	PredictionError, PredictionResult3,
+	build_board_layouts::{BoardLayout, build_board_layouts},
+	predict_board_configs,
+};
+
+/// Fractions of the surviving layouts in which a villager has each property
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct VillagerProbabilities {
+	pub evil: f64,
+	pub corrupted: f64,
+	pub lying: f64,
+	/// Unknown hidden good villagers don't count towards any archetype
+	pub archetypes: BTreeMap<VillagerArchetype, f64>,
+	pub execution_costs_hitpoints: f64,
+}
+
+/// Computes [`VillagerProbabilities`] for every villager over the layouts that satisfy the current testimonies
+pub fn villager_probabilities(
+	log: &impl Log,
+	state: &GameState,
+) -> Result<BTreeMap<VillagerIndex, VillagerProbabilities>, PredictionError> {
+	let zone = span!("Villager Probabilities");
+
+	let layouts: Vec<BoardLayout> = match predict_board_configs(
+		log,
+		state,
+		build_board_layouts(state)
+			.into_iter()
+			.map(|board_layout| (board_layout, None)),
+		true,
+	)? {
+		PredictionResult3::PredictionResult(prediction_result) => {
+			prediction_result.all_matching_layouts.into_keys().collect()
+		}
+		PredictionResult3::NeedMoreInfoResult(all_matching_layouts) => {
+			all_matching_layouts.into_keys().collect()
+		}
+	};
+
+	info!(logger: log, "Calculating villager probabilities over {} layouts", layouts.len());
+
+	let total_layouts = layouts.len() as f64;
+	let mut probabilities: BTreeMap<VillagerIndex, VillagerProbabilities> = state
+		.villager_indicies()
+		.map(|index| (index, VillagerProbabilities::default()))
+		.collect();
+
+	for layout in &layouts {
+		for (index, theoretical) in layout.villagers.iter().enumerate() {
+			let villager_probabilities = probabilities
+				.get_mut(&VillagerIndex(index))
+				.expect("Layout had more villagers than the game state");
+
+			if layout.evil_locations.contains(&VillagerIndex(index)) {
+				villager_probabilities.evil += 1.0;
+			}
+
+			if theoretical.inner.corrupted() {
+				villager_probabilities.corrupted += 1.0;
+			}
+
+			if theoretical.inner.will_lie() {
+				villager_probabilities.lying += 1.0;
+			}
+
+			if !theoretical.unknown_unrevealed_good() {
+				*villager_probabilities
+					.archetypes
+					.entry(theoretical.inner.true_identity().clone())
+					.or_default() += 1.0;
+			}
+
+			if let ExecutionResult::HealthDeduction(_) =
+				state.rule_set().execution_result(&theoretical.inner)
+			{
+				villager_probabilities.execution_costs_hitpoints += 1.0;
+			}
+		}
+	}
+
+	for villager_probabilities in probabilities.values_mut() {
+		villager_probabilities.evil /= total_layouts;
+		villager_probabilities.corrupted /= total_layouts;
+		villager_probabilities.lying /= total_layouts;
+		for archetype_probability in villager_probabilities.archetypes.values_mut() {
+			*archetype_probability /= total_layouts;
+		}
+		villager_probabilities.execution_costs_hitpoints /= total_layouts;
+	}
+
+	Ok(probabilities)
+}
@@ -1,28 +1,48 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, hash_map::Entry};
 
 use demon_bluff_gameplay_engine::{
+	Expression,
 	game_state::GameState,
-	villager::{Villager, VillagerIndex},
+	testimony::Testimony,
+	villager::{Villager, VillagerArchetype, VillagerIndex},
 };
+use log::debug;
+use serde::{Deserialize, Serialize};
+use tracy_client::span;
 
-use crate::PlayerAction;
+use crate::{
+	PlayerAction, PredictionError, build_board_layouts::build_board_layouts, planner::best_actions,
+	with_theoretical_testimony::LayoutWithTestimonyAssigments,
+};
+
+// how much each existing testimony about a villager is worth relative to the fraction of layouts revealing them would eliminate
+const TESTIMONY_REFERENCE_WEIGHT: f64 = 0.1;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RevealStrategy {
 	Simple,
 	FollowTestimony,
+	/// Takes whichever reveal or ability [`crate::rank_actions`] scores best. Reveals like [`RevealStrategy::Simple`] when nothing is expected to tell us anything
+	InformationGain,
 }
 
 impl RevealStrategy {
-	pub fn get_reveal(&self, log: &impl log::Log, game_state: &GameState) -> HashSet<PlayerAction> {
+	/// `layouts` are the ones left after testimonies, if anything has been revealed yet
+	pub fn get_reveal(
+		&self,
+		log: &impl log::Log,
+		game_state: &GameState,
+		layouts: Option<&[LayoutWithTestimonyAssigments]>,
+	) -> Result<HashSet<PlayerAction>, PredictionError> {
 		match self {
 			Self::Simple => simple_reveal(game_state),
 			Self::FollowTestimony => follow_testimony_reveal(log, game_state),
+			Self::InformationGain => information_gain_reveal(log, game_state, layouts),
 		}
 	}
 }
 
-fn simple_reveal(game_state: &GameState) -> HashSet<PlayerAction> {
+fn simple_reveal(game_state: &GameState) -> Result<HashSet<PlayerAction>, PredictionError> {
 	// Step three, need more info. Figure out how to best use reveals/abilities to gain info
 	// For now just reveal the first hidden index and we'll make it better later
 	let mut hidden_index = None;
@@ -39,11 +59,171 @@ fn simple_reveal(game_state: &GameState) -> HashSet<PlayerAction> {
 
 	let mut actions = HashSet::with_capacity(1);
 	actions.insert(PlayerAction::TryReveal(
-		hidden_index.expect("Reveal strategy was executed with no remaining villagers"),
+		hidden_index.ok_or(PredictionError::NothingToReveal)?,
 	));
-	actions
+	Ok(actions)
 }
 
-fn follow_testimony_reveal(log: &impl log::Log, game_state: &GameState) -> HashSet<PlayerAction> {
-	todo!()
+fn information_gain_reveal(
+	log: &impl log::Log,
+	game_state: &GameState,
+	layouts: Option<&[LayoutWithTestimonyAssigments]>,
+) -> Result<HashSet<PlayerAction>, PredictionError> {
+	let Some(layouts) = layouts else {
+		// nothing to go on yet, every villager is as good as the next
+		return simple_reveal(game_state);
+	};
+
+	let actions = best_actions(log, game_state, layouts);
+	if actions.is_empty() {
+		return simple_reveal(game_state);
+	}
+
+	Ok(actions)
+}
+
+fn follow_testimony_reveal(
+	log: &impl log::Log,
+	game_state: &GameState,
+) -> Result<HashSet<PlayerAction>, PredictionError> {
+	let zone = span!("Follow Testimony Reveal");
+
+	let mut revealable_indicies = Vec::new();
+	let mut references = HashMap::new();
+	game_state.iter_villagers(|index, villager| {
+		match villager {
+			Villager::Hidden(hidden_villager) => {
+				if !hidden_villager.cant_reveal() {
+					revealable_indicies.push(index);
+				}
+			}
+			Villager::Active(active_villager) => {
+				if let Some(testimony) = active_villager.instance().testimony() {
+					count_references(testimony, &mut references);
+				}
+			}
+			Villager::Confirmed(confirmed_villager) => {
+				if let Some(testimony) = confirmed_villager.instance().testimony() {
+					count_references(testimony, &mut references);
+				}
+			}
+		}
+		true
+	});
+
+	if revealable_indicies.len() == game_state.total_villagers() {
+		// nothing to follow yet, every villager is as good as the next
+		return simple_reveal(game_state);
+	}
+
+	let layouts = build_board_layouts(game_state);
+	let total_layouts = layouts.len();
+
+	let mut best_score = None;
+	let mut best_indicies = Vec::new();
+	for index in revealable_indicies {
+		// group the layouts by the archetype revealing this villager would show
+		let mut outcomes: HashMap<Option<VillagerArchetype>, usize> = HashMap::new();
+		for layout in layouts.iter() {
+			match outcomes.entry(layout.villagers[index.0].shown_archetype()) {
+				Entry::Occupied(mut occupied_entry) => *occupied_entry.get_mut() += 1,
+				Entry::Vacant(vacant_entry) => {
+					vacant_entry.insert(1);
+				}
+			}
+		}
+
+		// the expected fraction of layouts eliminated once we see the outcome
+		let expected_shrink = if total_layouts == 0 {
+			0.0
+		} else {
+			1.0 - outcomes
+				.values()
+				.map(|count| {
+					let fraction = *count as f64 / total_layouts as f64;
+					fraction * fraction
+				})
+				.sum::<f64>()
+		};
+
+		let reference_count = references.get(&index).cloned().unwrap_or(0);
+		let score = expected_shrink + reference_count as f64 * TESTIMONY_REFERENCE_WEIGHT;
+
+		debug!(logger: log, "Revealing {} scores {:.3} ({:.3} expected shrink of {} layouts, {} references)", index, score, expected_shrink, total_layouts, reference_count);
+
+		match best_score {
+			Some(current_best) if score < current_best - f64::EPSILON => {}
+			Some(current_best) if score <= current_best + f64::EPSILON => best_indicies.push(index),
+			_ => {
+				best_score = Some(score);
+				best_indicies.clear();
+				best_indicies.push(index);
+			}
+		}
+	}
+
+	if best_indicies.is_empty() {
+		return Err(PredictionError::NothingToReveal);
+	}
+
+	Ok(best_indicies
+		.into_iter()
+		.map(PlayerAction::TryReveal)
+		.collect())
+}
+
+fn count_references(
+	expression: &Expression<Testimony>,
+	references: &mut HashMap<VillagerIndex, usize>,
+) {
+	match expression {
+		Expression::Leaf(testimony) => {
+			for index in testimony_references(testimony) {
+				match references.entry(index) {
+					Entry::Occupied(mut occupied_entry) => *occupied_entry.get_mut() += 1,
+					Entry::Vacant(vacant_entry) => {
+						vacant_entry.insert(1);
+					}
+				}
+			}
+		}
+		Expression::Not(expression) => count_references(expression, references),
+		Expression::And(lhs, rhs) | Expression::Or(lhs, rhs) => {
+			count_references(lhs, references);
+			count_references(rhs, references);
+		}
+		Expression::MajorOr(expressions) => {
+			for expression in expressions {
+				count_references(expression, references);
+			}
+		}
+	}
+}
+
+fn testimony_references(testimony: &Testimony) -> Vec<VillagerIndex> {
+	match testimony {
+		Testimony::Good(index)
+		| Testimony::Evil(index)
+		| Testimony::Corrupt(index)
+		| Testimony::Lying(index)
+		| Testimony::Invincible(index)
+		| Testimony::FakeEvil(index)
+		| Testimony::SelfDestruct(index) => vec![index.clone()],
+		Testimony::Role(role_claim) => vec![role_claim.index().clone()],
+		Testimony::Affected(Some(affected_claim)) => vec![affected_claim.index().clone()],
+		Testimony::SlayAttempt(slay_result) => vec![slay_result.index().clone()],
+		Testimony::FortuneTeller(fortune_teller_claim) => fortune_teller_claim.targets().to_vec(),
+		Testimony::Druid(druid_claim) => druid_claim.targets().to_vec(),
+		Testimony::Bishop(bishop_claim) => bishop_claim.targets().to_vec(),
+		Testimony::Dreamer(dreamer_claim) => vec![dreamer_claim.target().clone()],
+		Testimony::Affected(None)
+		| Testimony::Cured(_)
+		| Testimony::Baker(_)
+		| Testimony::Confess(_)
+		| Testimony::Scout(_)
+		| Testimony::Enlightened(_)
+		| Testimony::Knitter(_)
+		| Testimony::Bard(_)
+		| Testimony::Architect(_) => Vec::new(),
+	}
 }
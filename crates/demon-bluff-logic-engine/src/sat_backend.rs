@@ -0,0 +1,231 @@
+use std::{
+	fs::{File, remove_file},
+	io::BufWriter,
+	path::PathBuf,
+	process::{Command, ExitStatus},
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+use thiserror::Error;
+use tracy_client::span;
+
+use crate::{cnf::Cnf, sat_solver::Solver};
+
+#[derive(Error, Debug)]
+pub enum SatBackendError {
+	#[error("Failed to run the SAT solver: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("The SAT solver exited with {0} without reporting SATISFIABLE or UNSATISFIABLE!")]
+	NoResult(ExitStatus),
+	#[error("The SAT solver reported an invalid literal: {0}")]
+	InvalidLiteral(String),
+}
+
+/// Something that can find models of a [`Cnf`]
+pub trait SatBackend {
+	/// Finds any model, assigning every variable including auxiliary ones. [`None`] if the formula is unsatisfiable
+	fn solve(&self, cnf: &Cnf) -> Result<Option<Vec<bool>>, SatBackendError>;
+
+	/// Every distinct assignment of the original variables that extends to a model
+	fn enumerate_models(&self, cnf: &Cnf) -> Result<Vec<Vec<bool>>, SatBackendError> {
+		let zone = span!("Backend Model Enumeration");
+		let mut cnf = cnf.clone();
+		let mut models = Vec::new();
+		while let Some(mut model) = self.solve(&cnf)? {
+			model.truncate(cnf.original_variables());
+
+			// block this model so the next solve has to find a different one
+			cnf.add_clause(
+				model
+					.iter()
+					.enumerate()
+					.map(|(variable_index, value)| {
+						let literal = Cnf::literal(variable_index);
+						if *value { -literal } else { literal }
+					})
+					.collect(),
+			);
+
+			models.push(model);
+		}
+
+		Ok(models)
+	}
+}
+
+/// The in process CDCL solver
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BuiltinSatBackend;
+
+impl SatBackend for BuiltinSatBackend {
+	fn solve(&self, cnf: &Cnf) -> Result<Option<Vec<bool>>, SatBackendError> {
+		let mut solver = Solver::new(cnf);
+		Ok(if solver.solve() {
+			Some(solver.model())
+		} else {
+			None
+		})
+	}
+
+	fn enumerate_models(&self, cnf: &Cnf) -> Result<Vec<Vec<bool>>, SatBackendError> {
+		Ok(Solver::new(cnf).enumerate_models(cnf.original_variables()))
+	}
+}
+
+/// Runs a DIMACS compatible solver binary (kissat, cadical, cryptominisat, etc.) once per model.
+/// The formula is written to a temporary file whose path is passed after `arguments`.
+/// The solver must print the standard `s` and `v` result lines to stdout
+#[derive(Debug, Clone)]
+pub struct ExternalSatBackend {
+	program: PathBuf,
+	arguments: Vec<String>,
+}
+
+impl ExternalSatBackend {
+	pub fn new(program: impl Into<PathBuf>, arguments: Vec<String>) -> Self {
+		Self {
+			program: program.into(),
+			arguments,
+		}
+	}
+}
+
+impl SatBackend for ExternalSatBackend {
+	fn solve(&self, cnf: &Cnf) -> Result<Option<Vec<bool>>, SatBackendError> {
+		static FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+		let zone = span!("External SAT Solve");
+		let path = std::env::temp_dir().join(format!(
+			"demon-bluff-{}-{}.cnf",
+			std::process::id(),
+			FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+		));
+
+		let output = {
+			let mut writer = BufWriter::new(File::create(&path)?);
+			cnf.write_dimacs(&mut writer)?;
+			drop(writer);
+
+			let output = Command::new(&self.program)
+				.args(&self.arguments)
+				.arg(&path)
+				.output();
+			remove_file(&path)?;
+			output?
+		};
+
+		parse_solver_output(
+			&String::from_utf8_lossy(&output.stdout),
+			cnf.total_variables(),
+		)?
+		.ok_or(SatBackendError::NoResult(output.status))
+	}
+}
+
+/// Parses the `s` and `v` lines of a solver's output. The outer [`None`] means no result line was found
+fn parse_solver_output(
+	output: &str,
+	total_variables: usize,
+) -> Result<Option<Option<Vec<bool>>>, SatBackendError> {
+	let mut satisfiable = None;
+	let mut model = vec![false; total_variables];
+	for line in output.lines() {
+		let mut tokens = line.split_whitespace();
+		match tokens.next() {
+			Some("s") => match tokens.next() {
+				Some("SATISFIABLE") => satisfiable = Some(true),
+				Some("UNSATISFIABLE") => satisfiable = Some(false),
+				_ => {}
+			},
+			Some("v") => {
+				for token in tokens {
+					let literal: i64 = token
+						.parse()
+						.map_err(|_| SatBackendError::InvalidLiteral(token.to_string()))?;
+					if literal == 0 {
+						break;
+					}
+
+					let variable_index = literal.unsigned_abs() as usize - 1;
+					if variable_index >= total_variables {
+						return Err(SatBackendError::InvalidLiteral(token.to_string()));
+					}
+
+					model[variable_index] = literal > 0;
+				}
+			}
+			_ => {}
+		}
+	}
+
+	Ok(satisfiable.map(|satisfiable| if satisfiable { Some(model) } else { None }))
+}
+
+#[test]
+fn test_parse_solver_output() {
+	assert_eq!(
+		Some(Some(vec![true, false, true])),
+		parse_solver_output("c some banner\ns SATISFIABLE\nv 1 -2\nv 3 0\n", 3).unwrap()
+	);
+	assert_eq!(
+		Some(None),
+		parse_solver_output("s UNSATISFIABLE\n", 3).unwrap()
+	);
+	assert_eq!(None, parse_solver_output("s UNKNOWN\n", 3).unwrap());
+	assert!(parse_solver_output("s SATISFIABLE\nv 1 -4 0\n", 3).is_err());
+}
+
+#[test]
+fn test_backends_agree() {
+	// (x1 | x2) & (!x1 | !x2) & (x3 | !x1)
+	let mut cnf = Cnf::new(3);
+	cnf.add_clause(vec![1, 2]);
+	cnf.add_clause(vec![-1, -2]);
+	cnf.add_clause(vec![3, -1]);
+
+	let mut builtin_models = BuiltinSatBackend.enumerate_models(&cnf).unwrap();
+	builtin_models.sort();
+	assert_eq!(
+		vec![
+			vec![false, true, false],
+			vec![false, true, true],
+			vec![true, false, true],
+		],
+		builtin_models
+	);
+
+	// the default enumeration only needs solve
+	struct SolveOnly;
+	impl SatBackend for SolveOnly {
+		fn solve(&self, cnf: &Cnf) -> Result<Option<Vec<bool>>, SatBackendError> {
+			BuiltinSatBackend.solve(cnf)
+		}
+	}
+
+	let mut solve_only_models = SolveOnly.enumerate_models(&cnf).unwrap();
+	solve_only_models.sort();
+	assert_eq!(builtin_models, solve_only_models);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_external_backend() {
+	let mut cnf = Cnf::new(2);
+	cnf.add_clause(vec![1, -2]);
+
+	// sh -c ignores the trailing file path, it becomes $0
+	let backend = ExternalSatBackend::new(
+		"sh",
+		vec![
+			"-c".to_string(),
+			"printf 'c fake solver\\ns SATISFIABLE\\nv 1 -2 0\\n'; exit 10".to_string(),
+		],
+	);
+	assert_eq!(Some(vec![true, false]), backend.solve(&cnf).unwrap());
+
+	let backend = ExternalSatBackend::new("sh", vec!["-c".to_string(), "exit 1".to_string()]);
+	assert!(matches!(
+		backend.solve(&cnf),
+		Err(SatBackendError::NoResult(_))
+	));
+}
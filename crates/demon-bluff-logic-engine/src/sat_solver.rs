@@ -0,0 +1,400 @@
+use tracy_client::span;
+
+use crate::cnf::Cnf;
+
+// literals are encoded as 2 * variable + negated so they can index the watch lists directly
+type Literal = usize;
+
+fn encode(dimacs_literal: i32) -> Literal {
+	let variable = dimacs_literal.unsigned_abs() as usize - 1;
+	2 * variable + (dimacs_literal < 0) as usize
+}
+
+fn variable(literal: Literal) -> usize {
+	literal >> 1
+}
+
+fn negate(literal: Literal) -> Literal {
+	literal ^ 1
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Value {
+	True,
+	False,
+	Unassigned,
+}
+
+/// Conflict driven clause learning solver with two watched literals and first UIP learning
+pub struct Solver {
+	clauses: Vec<Vec<Literal>>,
+	watches: Vec<Vec<usize>>,
+	assignment: Vec<Option<bool>>,
+	level: Vec<usize>,
+	reason: Vec<Option<usize>>,
+	trail: Vec<Literal>,
+	trail_limits: Vec<usize>,
+	propagation_head: usize,
+	unsatisfiable: bool,
+}
+
+impl Solver {
+	pub fn new(cnf: &Cnf) -> Self {
+		let total_variables = cnf.total_variables();
+		let mut solver = Self {
+			clauses: Vec::with_capacity(cnf.clauses().len()),
+			watches: vec![Vec::new(); total_variables * 2],
+			assignment: vec![None; total_variables],
+			level: vec![0; total_variables],
+			reason: vec![None; total_variables],
+			trail: Vec::with_capacity(total_variables),
+			trail_limits: Vec::new(),
+			propagation_head: 0,
+			unsatisfiable: false,
+		};
+
+		for clause in cnf.clauses() {
+			solver.add_clause(clause.iter().map(|literal| encode(*literal)).collect());
+		}
+
+		solver
+	}
+
+	/// Enumerates every assignment of the first `projected_variables` variables that can be extended to a model
+	pub fn enumerate_models(&mut self, projected_variables: usize) -> Vec<Vec<bool>> {
+		let zone = span!("Model Enumeration");
+		let mut models = Vec::new();
+		let mut active_blocking_clauses: Vec<usize> = Vec::new();
+		let mut in_blocking_clause = vec![false; self.watches.len()];
+		while self.solve() {
+			let model: Vec<bool> = self.assignment[..projected_variables]
+				.iter()
+				.map(|value| value.unwrap_or(false))
+				.collect();
+
+			models.push(model);
+
+			// decisions are made in variable order, so the projected variables are all decided before any auxiliary one
+			// and the model is pinned down by those decisions alone. Blocking just them keeps the clause short and lets
+			// the search backjump to flip the last one instead of restarting from scratch
+			let mut blocking_clause: Vec<Literal> = self
+				.trail_limits
+				.iter()
+				.map(|trail_limit| self.trail[*trail_limit])
+				.take_while(|literal| variable(*literal) < projected_variables)
+				.map(negate)
+				.collect();
+
+			if blocking_clause.is_empty() {
+				// every projected variable was forced, there is nothing else to find
+				break;
+			}
+
+			// the asserting literal goes first and the decision of the level being backjumped to second so they get watched
+			blocking_clause.reverse();
+			self.backtrack(blocking_clause.len() - 1);
+
+			// earlier blocking clauses containing this one are redundant now. Without dropping them every model adds
+			// another watch on the deepest decisions and each one gets slower to find than the last
+			for literal in &blocking_clause {
+				in_blocking_clause[*literal] = true;
+			}
+
+			let mut kept = 0;
+			for active_index in 0..active_blocking_clauses.len() {
+				let clause_index = active_blocking_clauses[active_index];
+				let clause = &self.clauses[clause_index];
+				let subsumed = clause
+					.iter()
+					.filter(|literal| in_blocking_clause[**literal])
+					.count() == blocking_clause.len();
+				if subsumed && self.reason[variable(clause[0])] != Some(clause_index) {
+					self.detach_clause(clause_index);
+				} else {
+					active_blocking_clauses[kept] = clause_index;
+					kept += 1;
+				}
+			}
+
+			active_blocking_clauses.truncate(kept);
+			for literal in &blocking_clause {
+				in_blocking_clause[*literal] = false;
+			}
+
+			let asserting_literal = blocking_clause[0];
+			if blocking_clause.len() == 1 {
+				self.enqueue(asserting_literal, None);
+			} else {
+				let clause_index = self.attach_clause(blocking_clause);
+				active_blocking_clauses.push(clause_index);
+				self.enqueue(asserting_literal, Some(clause_index));
+			}
+		}
+
+		models
+	}
+
+	pub fn solve(&mut self) -> bool {
+		if self.unsatisfiable {
+			return false;
+		}
+
+		loop {
+			if let Some(conflict) = self.propagate() {
+				if self.decision_level() == 0 {
+					self.unsatisfiable = true;
+					return false;
+				}
+
+				let (learnt_clause, backjump_level) = self.analyze(conflict);
+				self.backtrack(backjump_level);
+				let asserting_literal = learnt_clause[0];
+				if learnt_clause.len() == 1 {
+					self.enqueue(asserting_literal, None);
+				} else {
+					let clause_index = self.attach_clause(learnt_clause);
+					self.enqueue(asserting_literal, Some(clause_index));
+				}
+			} else {
+				let Some(decision_variable) =
+					self.assignment.iter().position(|value| value.is_none())
+				else {
+					return true;
+				};
+
+				self.trail_limits.push(self.trail.len());
+				// polarity barely matters since every model gets enumerated anyway
+				self.enqueue(2 * decision_variable + 1, None);
+			}
+		}
+	}
+
+	/// The current assignment of every variable, only meaningful after [`Solver::solve`] returns true
+	pub fn model(&self) -> Vec<bool> {
+		self.assignment
+			.iter()
+			.map(|value| value.unwrap_or(false))
+			.collect()
+	}
+
+	fn decision_level(&self) -> usize {
+		self.trail_limits.len()
+	}
+
+	fn value(&self, literal: Literal) -> Value {
+		match self.assignment[variable(literal)] {
+			Some(assigned) => {
+				if assigned != (literal & 1 == 1) {
+					Value::True
+				} else {
+					Value::False
+				}
+			}
+			None => Value::Unassigned,
+		}
+	}
+
+	fn enqueue(&mut self, literal: Literal, reason: Option<usize>) {
+		let variable = variable(literal);
+		debug_assert!(self.assignment[variable].is_none());
+		self.assignment[variable] = Some(literal & 1 == 0);
+		self.level[variable] = self.decision_level();
+		self.reason[variable] = reason;
+		self.trail.push(literal);
+	}
+
+	/// Only valid at decision level 0
+	fn add_clause(&mut self, mut clause: Vec<Literal>) {
+		debug_assert_eq!(0, self.decision_level());
+		if self.unsatisfiable {
+			return;
+		}
+
+		clause.sort_unstable();
+		clause.dedup();
+		if clause
+			.windows(2)
+			.any(|pair| variable(pair[0]) == variable(pair[1]))
+		{
+			// tautology
+			return;
+		}
+
+		if clause
+			.iter()
+			.any(|literal| self.value(*literal) == Value::True)
+		{
+			return;
+		}
+
+		clause.retain(|literal| self.value(*literal) == Value::Unassigned);
+		match clause.len() {
+			0 => self.unsatisfiable = true,
+			1 => self.enqueue(clause[0], None),
+			_ => {
+				self.attach_clause(clause);
+			}
+		}
+	}
+
+	fn attach_clause(&mut self, clause: Vec<Literal>) -> usize {
+		let clause_index = self.clauses.len();
+		self.watches[clause[0]].push(clause_index);
+		self.watches[clause[1]].push(clause_index);
+		self.clauses.push(clause);
+		clause_index
+	}
+
+	/// The clause must not be the reason for any assignment
+	fn detach_clause(&mut self, clause_index: usize) {
+		let clause = std::mem::take(&mut self.clauses[clause_index]);
+		for watched_literal in &clause[..2] {
+			self.watches[*watched_literal].retain(|watcher| *watcher != clause_index);
+		}
+	}
+
+	fn propagate(&mut self) -> Option<usize> {
+		while self.propagation_head < self.trail.len() {
+			let false_literal = negate(self.trail[self.propagation_head]);
+			self.propagation_head += 1;
+
+			let mut watchers = std::mem::take(&mut self.watches[false_literal]);
+			let mut conflict = None;
+			let mut kept = 0;
+			let mut watcher = 0;
+			while watcher < watchers.len() {
+				let clause_index = watchers[watcher];
+				watcher += 1;
+
+				// keep the false literal in the second slot
+				if self.clauses[clause_index][0] == false_literal {
+					self.clauses[clause_index].swap(0, 1);
+				}
+
+				let first = self.clauses[clause_index][0];
+				if self.value(first) == Value::True {
+					watchers[kept] = clause_index;
+					kept += 1;
+					continue;
+				}
+
+				let replacement = (2..self.clauses[clause_index].len()).find(|literal_index| {
+					self.value(self.clauses[clause_index][*literal_index]) != Value::False
+				});
+				if let Some(literal_index) = replacement {
+					self.clauses[clause_index].swap(1, literal_index);
+					let new_watch = self.clauses[clause_index][1];
+					self.watches[new_watch].push(clause_index);
+					continue;
+				}
+
+				watchers[kept] = clause_index;
+				kept += 1;
+				if self.value(first) == Value::False {
+					conflict = Some(clause_index);
+					while watcher < watchers.len() {
+						watchers[kept] = watchers[watcher];
+						kept += 1;
+						watcher += 1;
+					}
+				} else {
+					self.enqueue(first, Some(clause_index));
+				}
+			}
+
+			watchers.truncate(kept);
+			self.watches[false_literal] = watchers;
+
+			if conflict.is_some() {
+				return conflict;
+			}
+		}
+
+		None
+	}
+
+	fn analyze(&self, conflict: usize) -> (Vec<Literal>, usize) {
+		let current_level = self.decision_level();
+		let mut seen = vec![false; self.assignment.len()];
+		// slot 0 is reserved for the asserting literal
+		let mut learnt_clause = vec![0];
+		let mut pending_at_current_level = 0;
+		let mut trail_index = self.trail.len();
+		let mut clause_index = conflict;
+		let mut resolved_literal = None;
+
+		loop {
+			for literal in &self.clauses[clause_index] {
+				if Some(*literal) == resolved_literal {
+					continue;
+				}
+
+				let variable = variable(*literal);
+				if seen[variable] || self.level[variable] == 0 {
+					continue;
+				}
+
+				seen[variable] = true;
+				if self.level[variable] == current_level {
+					pending_at_current_level += 1;
+				} else {
+					learnt_clause.push(*literal);
+				}
+			}
+
+			// walk the trail back to the next literal involved in the conflict
+			loop {
+				trail_index -= 1;
+				if seen[variable(self.trail[trail_index])] {
+					break;
+				}
+			}
+
+			let literal = self.trail[trail_index];
+			seen[variable(literal)] = false;
+			pending_at_current_level -= 1;
+			if pending_at_current_level == 0 {
+				learnt_clause[0] = negate(literal);
+				break;
+			}
+
+			clause_index = self.reason[variable(literal)]
+				.expect("Only decisions lack a reason and there is one per level");
+			resolved_literal = Some(literal);
+		}
+
+		let mut backjump_level = 0;
+		if learnt_clause.len() > 1 {
+			// the second watch must be the literal that gets unassigned last
+			let mut highest_index = 1;
+			for literal_index in 2..learnt_clause.len() {
+				if self.level[variable(learnt_clause[literal_index])]
+					> self.level[variable(learnt_clause[highest_index])]
+				{
+					highest_index = literal_index;
+				}
+			}
+
+			learnt_clause.swap(1, highest_index);
+			backjump_level = self.level[variable(learnt_clause[1])];
+		}
+
+		(learnt_clause, backjump_level)
+	}
+
+	fn backtrack(&mut self, level: usize) {
+		if self.decision_level() <= level {
+			return;
+		}
+
+		let trail_limit = self.trail_limits[level];
+		for literal in self.trail.drain(trail_limit..) {
+			let variable = variable(literal);
+			self.assignment[variable] = None;
+			self.reason[variable] = None;
+		}
+
+		self.trail_limits.truncate(level);
+		self.propagation_head = self.trail.len();
+	}
+}
@@ -0,0 +1,1098 @@
+use std::{
+	collections::{BTreeSet, HashMap},
+	time::{Duration, Instant},
+};
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	affect::Affect,
+	deck::DeckError,
+	game_state::{
+		AbilityResult, Action, DrawStats, GameState, GameStateMutationError,
+		GameStateMutationResult, KillAttempt, KillData, KillResult, RevealResult, SlayerKill,
+		UnrevealedKillData, new_game,
+	},
+	rule_set::RuleSet,
+	testimony::{
+		AffectType, AffectedClaim, ArchitectClaim, BakerClaim, ConfessorClaim, Direction,
+		RoleClaim, ScoutClaim, Testimony, index_offset,
+	},
+	villager::{
+		ConfirmedVillager, Demon, GoodVillager, Minion, Outcast, Villager, VillagerArchetype,
+		VillagerIndex, VillagerInstance,
+	},
+};
+use itertools::Itertools;
+use log::{Log, info};
+use rand::{Rng, SeedableRng, seq::SliceRandom};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracy_client::span;
+
+use crate::{
+	PlayerAction, PredictionError, RevealStrategy, RolesInPlay,
+	build_board_layouts::{BoardLayout, TheoreticalVillager, apply_alchemist_cures},
+	build_expression_for_villager_set::IndexTestimony,
+	expression_assertion::evaluate_with_assignment,
+	optimized_expression::OptimizedExpression,
+	player_action::AbilityAttempt,
+	predict, validate_assignment,
+	with_theoretical_testimony::theoretical_testimonies,
+};
+
+#[derive(Error, Debug)]
+pub enum SimulationError {
+	#[error("The deck does not have enough {0} to satisfy the DrawStats")]
+	DeckTooSmall(&'static str),
+	#[error("Invalid deck: {0}")]
+	InvalidDeck(#[from] DeckError),
+	#[error("Prediction failed: {0}")]
+	Prediction(#[from] PredictionError),
+	#[error("The game state rejected a simulated action: {0}")]
+	Mutation(#[from] GameStateMutationError),
+	#[error("No outcome exists for the requested ability: {0}")]
+	NoAbilityOutcome(AbilityAttempt),
+	#[error("Seed {0} no longer deals the recorded ground truth")]
+	SeedMismatch(u64),
+	#[error("{0} has no testimony that matches whether they lie")]
+	NoConsistentTestimony(VillagerIndex),
+	#[error("The simulator can't deal the {0}'s fake outcast")]
+	UnsupportedArchetype(VillagerArchetype),
+}
+
+/// How a simulated game ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationOutcome {
+	pub won: bool,
+	pub actions: usize,
+	pub hitpoints: u8,
+}
+
+/// Serializable companion to a simulated [`GameState`]. Enough to deal the exact same game again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationRecord {
+	pub seed: u64,
+	pub deck: Vec<VillagerArchetype>,
+	pub draw_stats: DrawStats,
+	/// The ground truth as it was dealt, before any testimonies were given
+	pub truth: BoardLayout,
+	#[serde(default)]
+	pub rule_set: RuleSet,
+}
+
+/// A randomly dealt game. The ground truth is a fully revealed [`BoardLayout`] which answers every [`PlayerAction`].
+/// Every random choice, including the testimonies given later, comes from the seed so the same seed and deck always play out the same.
+/// Decks with a Baa are rejected. The layout builder doesn't model [`Affect::FakeOutcast`], so [`predict`] couldn't explain the boards it deals
+#[derive(Debug, Clone)]
+pub struct Simulation {
+	record: SimulationRecord,
+	truth: BoardLayout,
+	state: GameState,
+	rng: ChaCha8Rng,
+	predictions: usize,
+	prediction_time: Duration,
+}
+
+impl SimulationRecord {
+	/// Deals the recorded game again
+	pub fn replay(&self) -> Result<Simulation, SimulationError> {
+		let simulation = Simulation::deal(self.deck.clone(), self.draw_stats.clone(), self.seed)?
+			.with_rule_set(self.rule_set.clone());
+		if simulation.record != *self {
+			return Err(SimulationError::SeedMismatch(self.seed));
+		}
+
+		Ok(simulation)
+	}
+}
+
+impl Simulation {
+	/// Draws cards from the deck according to the [`DrawStats`], seats them randomly and applies every setup [`Affect`]
+	pub fn deal(
+		deck: Vec<VillagerArchetype>,
+		draw_stats: DrawStats,
+		seed: u64,
+	) -> Result<Self, SimulationError> {
+		let zone = span!("Deal");
+		if let Some(archetype) = deck.iter().find(|archetype| {
+			matches!(
+				archetype.affect(draw_stats.total_villagers(), None),
+				Some(Affect::FakeOutcast)
+			)
+		}) {
+			return Err(SimulationError::UnsupportedArchetype(archetype.clone()));
+		}
+
+		let mut seeded_rng = ChaCha8Rng::seed_from_u64(seed);
+		let rng = &mut seeded_rng;
+
+		let mut identities = Vec::with_capacity(draw_stats.total_villagers());
+		identities.extend(draw(
+			&deck,
+			draw_stats.villagers(),
+			"villagers",
+			rng,
+			|archetype| matches!(archetype, VillagerArchetype::GoodVillager(_)),
+		)?);
+		identities.extend(draw(
+			&deck,
+			draw_stats.outcasts(),
+			"outcasts",
+			rng,
+			|archetype| matches!(archetype, VillagerArchetype::Outcast(_)),
+		)?);
+		identities.extend(draw(
+			&deck,
+			draw_stats.minions(),
+			"minions",
+			rng,
+			|archetype| matches!(archetype, VillagerArchetype::Minion(_)),
+		)?);
+		identities.extend(draw(
+			&deck,
+			draw_stats.demons(),
+			"demons",
+			rng,
+			|archetype| matches!(archetype, VillagerArchetype::Demon(_)),
+		)?);
+		identities.shuffle(rng);
+
+		let total_villagers = identities.len();
+		let mut affections: Vec<Option<AffectType>> = vec![None; total_villagers];
+
+		// counsellors turn a neighbouring villager into an outcast that wasn't drawn
+		for counsellor_index in 0..total_villagers {
+			if identities[counsellor_index] != VillagerArchetype::Minion(Minion::Counsellor) {
+				continue;
+			}
+
+			let Some(target_index) = neighbours(counsellor_index, total_villagers)
+				.filter(|index| matches!(identities[*index], VillagerArchetype::GoodVillager(_)))
+				.collect::<Vec<usize>>()
+				.choose(rng)
+				.cloned()
+			else {
+				continue;
+			};
+
+			let undrawn_outcasts: Vec<VillagerArchetype> = deck
+				.iter()
+				.filter(|archetype| {
+					matches!(archetype, VillagerArchetype::Outcast(_))
+						&& !identities.contains(archetype)
+				})
+				.cloned()
+				.collect();
+			if let Some(outcast) = undrawn_outcasts.choose(rng) {
+				identities[target_index] = outcast.clone();
+				affections[target_index] = Some(AffectType::Outcasted);
+			}
+		}
+
+		// shamans turn a villager into a copy of another
+		if identities.contains(&VillagerArchetype::Minion(Minion::Shaman)) {
+			let good_indicies: Vec<usize> = (0..total_villagers)
+				.filter(|index| matches!(identities[*index], VillagerArchetype::GoodVillager(_)))
+				.collect();
+			if good_indicies.len() > 1 {
+				let chosen: Vec<usize> = good_indicies.choose_multiple(rng, 2).cloned().collect();
+				identities[chosen[1]] = identities[chosen[0]].clone();
+				affections[chosen[1]] = Some(AffectType::Cloned);
+			}
+		}
+
+		// drunks and evils pretend to be villagers that aren't in play
+		let mut visible_archetypes: BTreeSet<VillagerArchetype> = identities
+			.iter()
+			.filter(|archetype| !archetype.is_evil() && !hides_behind_a_villager(archetype))
+			.cloned()
+			.collect();
+		let mut villagers = Vec::with_capacity(total_villagers);
+		for (index, identity) in identities.iter().enumerate() {
+			let villager = if *identity == VillagerArchetype::Outcast(Outcast::Doppelganger) {
+				let copied_archetype = identities
+					.iter()
+					.filter(|archetype| matches!(archetype, VillagerArchetype::GoodVillager(_)))
+					.collect::<Vec<&VillagerArchetype>>()
+					.choose(rng)
+					.map(|archetype| (*archetype).clone())
+					.ok_or(SimulationError::DeckTooSmall(
+						"villagers for the doppelganger",
+					))?;
+				affections[index] = Some(AffectType::Cloned);
+				ConfirmedVillager::new(
+					VillagerInstance::new(copied_archetype, None),
+					Some(identity.clone()),
+					false,
+				)
+			} else if identity.is_evil() || hides_behind_a_villager(identity) {
+				let disguises: Vec<&VillagerArchetype> = deck
+					.iter()
+					.filter(|archetype| {
+						matches!(archetype, VillagerArchetype::GoodVillager(_))
+							&& !visible_archetypes.contains(archetype)
+					})
+					.collect();
+				let disguise = (*disguises
+					.choose(rng)
+					.ok_or(SimulationError::DeckTooSmall("villagers to disguise as"))?)
+				.clone();
+				visible_archetypes.insert(disguise.clone());
+				ConfirmedVillager::new(
+					VillagerInstance::new(disguise, None),
+					Some(identity.clone()),
+					identity.starts_corrupted(),
+				)
+			} else {
+				ConfirmedVillager::new(VillagerInstance::new(identity.clone(), None), None, false)
+			};
+
+			let mut theoretical = TheoreticalVillager::new(villager, false, false);
+			theoretical.affection = affections[index].clone();
+			villagers.push(theoretical);
+		}
+
+		let mut truth = BoardLayout {
+			villagers,
+			evil_locations: BTreeSet::new(),
+			description: String::new(),
+		};
+
+		// poisoners and puppeteers pick a neighbour, in a random order since they can cancel each other out
+		let mut adjacent_affectors: Vec<usize> = (0..total_villagers)
+			.filter(|index| {
+				matches!(
+					identities[*index].affect(total_villagers, Some(VillagerIndex(*index))),
+					Some(Affect::Corrupt(_) | Affect::Puppet(_))
+				) && !matches!(
+					identities[*index],
+					VillagerArchetype::Demon(Demon::Pooka)
+						| VillagerArchetype::Outcast(Outcast::PlagueDoctor)
+				)
+			})
+			.collect();
+		adjacent_affectors.shuffle(rng);
+		for affector_index in adjacent_affectors {
+			let target_index = index_offset(
+				&VillagerIndex(affector_index),
+				total_villagers,
+				1,
+				rng.gen_bool(0.5),
+			);
+			match identities[affector_index].affect(total_villagers, None) {
+				Some(Affect::Corrupt(_)) => corrupt(&mut truth, target_index.0, true),
+				Some(Affect::Puppet(_)) => {
+					let target = &mut truth.villagers[target_index.0];
+					if target.inner.true_identity().can_be_converted() {
+						target.inner = ConfirmedVillager::new(
+							target.inner.instance().clone(),
+							Some(VillagerArchetype::Minion(Minion::Puppet)),
+							false,
+						);
+						target.affection = Some(AffectType::Puppeted);
+					}
+				}
+				_ => unreachable!("Only corrupting and puppeting affectors were collected"),
+			}
+		}
+
+		for pooka_index in (0..total_villagers)
+			.filter(|index| identities[*index] == VillagerArchetype::Demon(Demon::Pooka))
+		{
+			for target_index in neighbours(pooka_index, total_villagers) {
+				corrupt(&mut truth, target_index, true);
+			}
+		}
+
+		if identities.contains(&VillagerArchetype::Outcast(Outcast::PlagueDoctor)) {
+			let corruptible_indicies: Vec<usize> = (0..total_villagers)
+				.filter(|index| {
+					let theoretical = &truth.villagers[*index];
+					!theoretical.inner.corrupted()
+						&& theoretical.inner.true_identity().can_be_corrupted()
+				})
+				.collect();
+			if let Some(target_index) = corruptible_indicies.choose(rng) {
+				corrupt(&mut truth, *target_index, false);
+			}
+		}
+
+		let mut truth = apply_alchemist_cures(truth);
+		for (index, theoretical) in truth.villagers.iter_mut().enumerate() {
+			theoretical.revealed = true;
+			if theoretical.inner.true_identity().is_evil() {
+				truth.evil_locations.insert(VillagerIndex(index));
+			}
+		}
+
+		truth.description = truth
+			.villagers
+			.iter()
+			.enumerate()
+			.map(
+				|(index, theoretical)| match theoretical.inner.hidden_identity() {
+					Some(true_identity) => format!(
+						"{}: {} (actually a {})",
+						VillagerIndex(index),
+						theoretical.inner.instance().archetype(),
+						true_identity
+					),
+					None => format!(
+						"{}: {}",
+						VillagerIndex(index),
+						theoretical.inner.instance().archetype()
+					),
+				},
+			)
+			.join(", ");
+
+		let night_effects_active = identities
+			.iter()
+			.any(|archetype| archetype.has_night_action());
+		let total_evils = truth.evil_locations.len();
+		let record = SimulationRecord {
+			seed,
+			deck: deck.clone(),
+			draw_stats: draw_stats.clone(),
+			truth: truth.clone(),
+			rule_set: RuleSet::current(),
+		};
+		let state = new_game(deck, draw_stats, total_evils, night_effects_active)?;
+
+		Ok(Self {
+			record,
+			truth,
+			state,
+			rng: seeded_rng,
+			predictions: 0,
+			prediction_time: Duration::ZERO,
+		})
+	}
+
+	/// Plays the game under a different [`RuleSet`] than the current one
+	pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+		self.record.rule_set = rule_set.clone();
+		self.state = self.state.with_rule_set(rule_set);
+		self
+	}
+
+	pub fn record(&self) -> &SimulationRecord {
+		&self.record
+	}
+
+	/// How many times [`predict`] has been called while playing
+	pub fn predictions(&self) -> usize {
+		self.predictions
+	}
+
+	/// Total time spent in [`predict`] while playing, including a call that failed
+	pub fn prediction_time(&self) -> Duration {
+		self.prediction_time
+	}
+
+	pub fn truth(&self) -> &BoardLayout {
+		&self.truth
+	}
+
+	pub fn state(&self) -> &GameState {
+		&self.state
+	}
+
+	/// Whether the next action has to be [`Action::LilisNightKill`]
+	pub fn must_take_night_action(&self) -> bool {
+		self.state.must_take_night_action()
+	}
+
+	/// What the game would reveal in response to the player taking an action
+	pub fn answer(
+		&mut self,
+		log: &impl Log,
+		player_action: &PlayerAction,
+	) -> Result<Action, SimulationError> {
+		Ok(match player_action {
+			PlayerAction::TryReveal(index) => {
+				if self.witch_blocks_reveal(index) {
+					info!(logger: log, "The witch blocked the reveal of {}", index);
+					Action::TryReveal(RevealResult::new(index.clone(), None))
+				} else {
+					Action::TryReveal(RevealResult::new(
+						index.clone(),
+						Some(self.reveal_instance(log, index)?),
+					))
+				}
+			}
+			PlayerAction::TryExecute(index) => {
+				let kill_result = self.kill_result(log, index)?;
+				self.truth.villagers[index.0].actually_dead = true;
+				Action::TryExecute(KillAttempt::new(index.clone(), Some(kill_result)))
+			}
+			PlayerAction::Ability(ability_attempt) => {
+				let source = ability_attempt.source();
+				let outcomes: Vec<BoardLayout> =
+					theoretical_testimonies(&self.state, &self.truth, source.clone())
+						.filter(|(_, generated_attempt, _)| generated_attempt == ability_attempt)
+						.map(|(next_layout, _, _)| next_layout)
+						.collect();
+				let next_layout = outcomes
+					.choose(&mut self.rng)
+					.ok_or_else(|| SimulationError::NoAbilityOutcome(ability_attempt.clone()))?;
+				let testimony = next_layout.villagers[source.0]
+					.inner
+					.instance()
+					.testimony()
+					.clone()
+					.expect("Generated ability outcomes should have a testimony");
+
+				let mut slayer_kill = None;
+				if let Expression::Leaf(Testimony::SlayAttempt(slay_result)) = &testimony
+					&& slay_result.slayed()
+				{
+					let target = slay_result.index();
+					slayer_kill = Some(SlayerKill::new(
+						target.clone(),
+						self.kill_result(log, target)?,
+					));
+					self.truth.villagers[target.0].actually_dead = true;
+				}
+
+				info!(logger: log, "{} answered with {}", ability_attempt, testimony);
+				self.truth.villagers[source.0]
+					.inner
+					.instance_mut()
+					.set_testimony(testimony.clone());
+				Action::Ability(AbilityResult::new(
+					source.clone(),
+					Some(testimony),
+					slayer_kill,
+				))
+			}
+			PlayerAction::LilisNightKill(target) => self.lilis_kills(target.clone()),
+		})
+	}
+
+	fn lilis_alive(&self) -> bool {
+		self.truth.villagers.iter().any(|theoretical| {
+			*theoretical.inner.true_identity() == VillagerArchetype::Demon(Demon::Lilis)
+				&& !theoretical.actually_dead
+		})
+	}
+
+	fn lilis_kills(&mut self, target: Option<VillagerIndex>) -> Action {
+		if !self.lilis_alive() {
+			return Action::LilisNightKill(None);
+		}
+
+		if let Some(target) = &target {
+			let theoretical = &mut self.truth.villagers[target.0];
+			theoretical.actually_dead = true;
+			theoretical.affection = Some(AffectType::Killed);
+		}
+
+		Action::LilisNightKill(target)
+	}
+
+	/// Lilis kills a random unrevealed villager, if she's still alive
+	pub fn night_kill(&mut self) -> Action {
+		if !self.lilis_alive() {
+			return Action::LilisNightKill(None);
+		}
+
+		let targets: Vec<VillagerIndex> = self
+			.state
+			.villager_indicies()
+			.filter(|index| match self.state.villager(index) {
+				Villager::Hidden(hidden_villager) => {
+					!hidden_villager.dead() && !hidden_villager.cant_kill()
+				}
+				Villager::Active(_) | Villager::Confirmed(_) => false,
+			})
+			.collect();
+		let target = targets.choose(&mut self.rng).cloned();
+		self.lilis_kills(target)
+	}
+
+	/// Plays the game out, always taking a random one of the predicted actions
+	pub fn play(
+		&mut self,
+		log: &impl Log,
+		reveal_strategy: RevealStrategy,
+	) -> Result<SimulationOutcome, SimulationError> {
+		let zone = span!("Simulate Game");
+		info!(logger: log, "Simulating: {}", self.truth.description);
+
+		let mut actions = 0;
+		loop {
+			let action = if self.must_take_night_action() {
+				self.night_kill()
+			} else {
+				let prediction_start = Instant::now();
+				let prediction = predict(log, &self.state, reveal_strategy);
+				self.predictions += 1;
+				self.prediction_time += prediction_start.elapsed();
+
+				let mut player_actions: Vec<PlayerAction> = prediction?.into_iter().collect();
+				// hash set order isn't stable, don't let it affect the rng
+				player_actions.sort_by_cached_key(|player_action| player_action.to_string());
+				let player_action = player_actions
+					.choose(&mut self.rng)
+					.expect("Predict should always return at least one action")
+					.clone();
+				info!(logger: log, "Simulated player chose: {}", player_action);
+				self.answer(log, &player_action)?
+			};
+
+			actions += 1;
+			match self.state.mutate(action)? {
+				GameStateMutationResult::Win => {
+					return Ok(SimulationOutcome {
+						won: true,
+						actions,
+						hitpoints: self.state.hitpoints(),
+					});
+				}
+				GameStateMutationResult::Loss => {
+					return Ok(SimulationOutcome {
+						won: false,
+						actions,
+						hitpoints: self.state.hitpoints(),
+					});
+				}
+				GameStateMutationResult::Continue => {}
+			}
+		}
+	}
+
+	fn witch_blocks_reveal(&self, index: &VillagerIndex) -> bool {
+		let witch_alive = self.truth.villagers.iter().any(|theoretical| {
+			*theoretical.inner.true_identity() == VillagerArchetype::Minion(Minion::Witch)
+				&& !theoretical.actually_dead
+		});
+		if !witch_alive {
+			return false;
+		}
+
+		// the witch blocks the last reveals
+		let revealable_villagers = self
+			.state
+			.villagers()
+			.iter()
+			.filter(|villager| match villager {
+				Villager::Hidden(hidden_villager) => !hidden_villager.cant_reveal(),
+				Villager::Active(_) | Villager::Confirmed(_) => false,
+			})
+			.count();
+		revealable_villagers <= self.state.rule_set().witch_blocked_reveals()
+			&& *self.truth.villagers[index.0].inner.true_identity()
+				!= VillagerArchetype::Minion(Minion::Witch)
+	}
+
+	fn kill_result(
+		&mut self,
+		log: &impl Log,
+		index: &VillagerIndex,
+	) -> Result<KillResult, SimulationError> {
+		let theoretical = &self.truth.villagers[index.0];
+		let kill_data = KillData::new(
+			theoretical.inner.hidden_identity().clone(),
+			theoretical.inner.corrupted(),
+		)
+		.expect("Only identities that can't be corrupted are hidden");
+
+		Ok(match self.state.villager(index) {
+			Villager::Hidden(_) => KillResult::Unrevealed(UnrevealedKillData::new(
+				self.reveal_instance(log, index)?,
+				kill_data,
+			)),
+			Villager::Active(_) | Villager::Confirmed(_) => KillResult::Revealed(kill_data),
+		})
+	}
+
+	/// The instance as it appears when revealed, generating its testimony the first time
+	fn reveal_instance(
+		&mut self,
+		log: &impl Log,
+		index: &VillagerIndex,
+	) -> Result<VillagerInstance, SimulationError> {
+		let instance = self.truth.villagers[index.0].inner.instance();
+		if instance.archetype().has_action() || instance.testimony().is_some() {
+			return Ok(instance.clone());
+		}
+
+		let testimony = self.reveal_testimony(log, index)?;
+		let instance = self.truth.villagers[index.0].inner.instance_mut();
+		instance.set_testimony(testimony);
+		Ok(instance.clone())
+	}
+
+	/// A testimony that is false exactly when the villager lies. Never one that contradicts the ground truth
+	fn reveal_testimony(
+		&mut self,
+		log: &impl Log,
+		index: &VillagerIndex,
+	) -> Result<Expression<Testimony>, SimulationError> {
+		let theoretical = &self.truth.villagers[index.0];
+		let archetype = theoretical.inner.instance().archetype();
+		let will_lie = theoretical.inner.will_lie();
+		let total_villagers = self.truth.villagers.len();
+		let other_indicies = || {
+			(0..total_villagers)
+				.filter(move |other_index| *other_index != index.0)
+				.map(VillagerIndex)
+		};
+
+		let candidates: Vec<Expression<Testimony>> = match archetype {
+			VillagerArchetype::GoodVillager(good_villager) => match good_villager {
+				GoodVillager::Alchemist
+				| GoodVillager::Bard
+				| GoodVillager::Bishop
+				| GoodVillager::Knitter
+				| GoodVillager::Oracle
+				| GoodVillager::Poet => {
+					// these already respect whether the testifier lies
+					let generated: Vec<Expression<Testimony>> =
+						theoretical_testimonies(&self.state, &self.truth, index.clone())
+							.filter_map(|(next_layout, _, _)| {
+								next_layout.villagers[index.0]
+									.inner
+									.instance()
+									.testimony()
+									.clone()
+							})
+							.collect();
+					return generated
+						.choose(&mut self.rng)
+						.cloned()
+						.ok_or_else(|| SimulationError::NoConsistentTestimony(index.clone()));
+				}
+				GoodVillager::Baker => {
+					// only a truthful original baker has always been a baker
+					let true_identity = theoretical.inner.true_identity();
+					let baker_is_real = *true_identity
+						== VillagerArchetype::GoodVillager(GoodVillager::Baker)
+						|| *true_identity == VillagerArchetype::Outcast(Outcast::Doppelganger);
+					let was = if baker_is_real && !will_lie {
+						None
+					} else {
+						self.state
+							.deck()
+							.iter()
+							.filter_map(|archetype| match archetype {
+								VillagerArchetype::GoodVillager(good_villager)
+									if *good_villager != GoodVillager::Baker =>
+								{
+									Some(good_villager.clone())
+								}
+								_ => None,
+							})
+							.collect::<Vec<GoodVillager>>()
+							.choose(&mut self.rng)
+							.cloned()
+					};
+
+					return Ok(Expression::Leaf(Testimony::Baker(BakerClaim::new(was))));
+				}
+				GoodVillager::Architect => [
+					ArchitectClaim::Left,
+					ArchitectClaim::Right,
+					ArchitectClaim::Equal,
+				]
+				.into_iter()
+				.map(|claim| Expression::Leaf(Testimony::Architect(claim)))
+				.collect(),
+				GoodVillager::Confessor => [ConfessorClaim::Good, ConfessorClaim::Dizzy]
+					.into_iter()
+					.map(|claim| Expression::Leaf(Testimony::Confess(claim)))
+					.collect(),
+				GoodVillager::Empress => other_indicies()
+					.combinations(3)
+					.map(|suspects| Testimony::empress(&suspects.try_into().unwrap()))
+					.collect(),
+				GoodVillager::Enlightened => [
+					Direction::Clockwise,
+					Direction::CounterClockwise,
+					Direction::Equidistant,
+				]
+				.into_iter()
+				.map(|direction| Expression::Leaf(Testimony::Enlightened(direction)))
+				.collect(),
+				GoodVillager::Gemcrafter => other_indicies()
+					.map(|other_index| Expression::Leaf(Testimony::Good(other_index)))
+					.collect(),
+				GoodVillager::Hunter => (1..=total_villagers / 2)
+					.map(|distance| Testimony::hunter(index, distance, total_villagers))
+					.collect(),
+				GoodVillager::Knight => {
+					vec![Expression::Leaf(Testimony::Invincible(index.clone()))]
+				}
+				GoodVillager::Lover => (0..=2)
+					.map(|amount| Testimony::lover(index, amount, total_villagers))
+					.collect(),
+				GoodVillager::Medium => other_indicies()
+					.cartesian_product(
+						self.state
+							.deck()
+							.iter()
+							.filter(|archetype| {
+								matches!(archetype, VillagerArchetype::GoodVillager(_))
+							})
+							.cloned()
+							.collect::<Vec<VillagerArchetype>>(),
+					)
+					.map(|(other_index, archetype)| {
+						Expression::Leaf(Testimony::Role(RoleClaim::new(other_index, archetype)))
+					})
+					.collect(),
+				GoodVillager::Scout => self
+					.state
+					.deck()
+					.iter()
+					.filter(|archetype| archetype.is_evil())
+					.unique()
+					.cartesian_product(1..=total_villagers)
+					.map(|(archetype, distance)| {
+						Expression::Leaf(Testimony::Scout(ScoutClaim::new(
+							archetype.clone(),
+							distance,
+						)))
+					})
+					.collect(),
+				GoodVillager::Witness => {
+					std::iter::once(Expression::Leaf(Testimony::Affected(None)))
+						.chain(
+							other_indicies()
+								.cartesian_product([
+									AffectType::Puppeted,
+									AffectType::CorruptedByEvil,
+									AffectType::Outcasted,
+									AffectType::Cloned,
+									AffectType::Killed,
+								])
+								.map(|(other_index, affect_type)| {
+									Expression::Leaf(Testimony::Affected(Some(AffectedClaim::new(
+										other_index,
+										affect_type,
+									))))
+								}),
+						)
+						.collect()
+				}
+				GoodVillager::Dreamer
+				| GoodVillager::Druid
+				| GoodVillager::FortuneTeller
+				| GoodVillager::Jester
+				| GoodVillager::Judge
+				| GoodVillager::Slayer => {
+					unreachable!("A {} reveals with an ability, not a testimony", archetype)
+				}
+			},
+			VillagerArchetype::Outcast(outcast) => match outcast {
+				Outcast::Wretch => vec![Expression::Leaf(Testimony::FakeEvil(index.clone()))],
+				Outcast::Bombardier => {
+					vec![Expression::Leaf(Testimony::SelfDestruct(index.clone()))]
+				}
+				Outcast::Drunk | Outcast::Doppelganger | Outcast::PlagueDoctor => {
+					unreachable!("A {} never reveals with a testimony", archetype)
+				}
+			},
+			VillagerArchetype::Minion(_) | VillagerArchetype::Demon(_) => {
+				unreachable!("Evils always reveal disguised")
+			}
+		};
+
+		let consistent_candidates: Vec<&Expression<Testimony>> = candidates
+			.iter()
+			.filter(|candidate| self.testimony_holds(log, index, candidate) != will_lie)
+			.collect();
+		// showing any other testimony would deal a board no layout explains
+		consistent_candidates
+			.choose(&mut self.rng)
+			.map(|testimony| (*testimony).clone())
+			.ok_or_else(|| SimulationError::NoConsistentTestimony(index.clone()))
+	}
+
+	/// Whether the testimony is true for the ground truth
+	fn testimony_holds(
+		&self,
+		log: &impl Log,
+		testifier: &VillagerIndex,
+		testimony: &Expression<Testimony>,
+	) -> bool {
+		let mut layout = self.truth.clone();
+		layout.villagers[testifier.0]
+			.inner
+			.instance_mut()
+			.set_testimony(testimony.clone());
+
+		let roles_in_play = RolesInPlay::new(&self.state);
+
+		let leaf_values: HashMap<Testimony, bool> = OptimizedExpression::new(testimony)
+			.variables()
+			.iter()
+			.map(|leaf| {
+				let holds = validate_assignment(
+					log,
+					&vec![true],
+					&[IndexTestimony::new(testifier.clone(), leaf.clone())],
+					&layout,
+					&self.state,
+					roles_in_play,
+				);
+				(leaf.clone(), holds)
+			})
+			.collect();
+
+		evaluate_with_assignment(testimony, &leaf_values)
+	}
+}
+
+/// Draws `count` cards matching the filter without replacement
+fn draw<F>(
+	deck: &[VillagerArchetype],
+	count: usize,
+	kind: &'static str,
+	rng: &mut impl Rng,
+	filter: F,
+) -> Result<Vec<VillagerArchetype>, SimulationError>
+where
+	F: Fn(&VillagerArchetype) -> bool,
+{
+	let candidates: Vec<&VillagerArchetype> =
+		deck.iter().filter(|archetype| filter(archetype)).collect();
+	if candidates.len() < count {
+		return Err(SimulationError::DeckTooSmall(kind));
+	}
+
+	Ok(candidates
+		.choose_multiple(rng, count)
+		.map(|archetype| (*archetype).clone())
+		.collect())
+}
+
+/// Outcasts that are dealt looking like a villager
+fn hides_behind_a_villager(archetype: &VillagerArchetype) -> bool {
+	archetype.starts_corrupted()
+}
+
+fn neighbours(index: usize, total_villagers: usize) -> impl Iterator<Item = usize> {
+	let index = VillagerIndex(index);
+	[
+		index_offset(&index, total_villagers, 1, true).0,
+		index_offset(&index, total_villagers, 1, false).0,
+	]
+	.into_iter()
+}
+
+fn corrupt(layout: &mut BoardLayout, index: usize, by_evil: bool) {
+	let theoretical = &mut layout.villagers[index];
+	if !theoretical.inner.corrupted() && theoretical.inner.true_identity().can_be_corrupted() {
+		theoretical.inner.set_corrupted(true);
+		if by_evil {
+			theoretical.affection = Some(AffectType::CorruptedByEvil);
+		}
+	}
+}
+
+#[test]
+fn test_simulated_games() {
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::GoodVillager(GoodVillager::Knight),
+		VillagerArchetype::GoodVillager(GoodVillager::Enlightened),
+		VillagerArchetype::Outcast(Outcast::Drunk),
+		VillagerArchetype::Minion(Minion::Poisoner),
+		VillagerArchetype::Demon(Demon::Pooka),
+	];
+
+	let log = log::logger();
+	for seed in 0..3 {
+		let mut simulation =
+			Simulation::deal(deck.clone(), DrawStats::new(4, 1, 1, 1), seed).unwrap();
+
+		assert_eq!(7, simulation.truth().villagers.len());
+		assert!(simulation.truth().evil_locations.iter().all(|index| {
+			simulation.truth().villagers[index.0]
+				.inner
+				.true_identity()
+				.is_evil()
+		}));
+		assert!(
+			simulation
+				.truth()
+				.villagers
+				.iter()
+				.all(|theoretical| theoretical.inner.instance().archetype()
+					!= &VillagerArchetype::Outcast(Outcast::Drunk))
+		);
+
+		let outcome = simulation.play(&log, RevealStrategy::Simple).unwrap();
+		assert!(outcome.actions >= 1);
+		assert_eq!(
+			outcome.won,
+			simulation.state().evils_killed() >= simulation.state().total_evils()
+		);
+	}
+
+	assert!(matches!(
+		Simulation::deal(deck, DrawStats::new(8, 1, 1, 1), 0),
+		Err(SimulationError::DeckTooSmall("villagers"))
+	));
+}
+
+// deck builder decks can duplicate archetypes and go past the preset board sizes
+#[test]
+fn test_custom_deck_simulated_games() {
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Knight),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+		VillagerArchetype::GoodVillager(GoodVillager::Bishop),
+		VillagerArchetype::GoodVillager(GoodVillager::Oracle),
+		VillagerArchetype::GoodVillager(GoodVillager::Knitter),
+		VillagerArchetype::GoodVillager(GoodVillager::Scout),
+		VillagerArchetype::GoodVillager(GoodVillager::Empress),
+		VillagerArchetype::Outcast(Outcast::Wretch),
+		VillagerArchetype::Minion(Minion::Minion),
+		VillagerArchetype::Minion(Minion::Minion),
+		VillagerArchetype::Demon(Demon::Lilis),
+	];
+
+	let log = log::logger();
+	for seed in 0..3 {
+		let mut simulation =
+			Simulation::deal(deck.clone(), DrawStats::new(6, 1, 2, 1), seed).unwrap();
+		assert_eq!(10, simulation.truth().villagers.len());
+
+		let outcome = simulation.play(&log, RevealStrategy::Simple).unwrap();
+		assert_eq!(
+			outcome.won,
+			simulation.state().evils_killed() >= simulation.state().total_evils()
+		);
+	}
+
+	assert!(matches!(
+		Simulation::deal(deck.clone(), DrawStats::new(6, 1, 2, 2), 0),
+		Err(SimulationError::DeckTooSmall("demons"))
+	));
+
+	let mut baa_deck = deck;
+	baa_deck.push(VillagerArchetype::Demon(Demon::Baa));
+	assert!(matches!(
+		Simulation::deal(baa_deck, DrawStats::new(6, 1, 2, 1), 0),
+		Err(SimulationError::UnsupportedArchetype(
+			VillagerArchetype::Demon(Demon::Baa)
+		))
+	));
+}
+
+#[test]
+fn test_seeded_simulation_replays() {
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::GoodVillager(GoodVillager::Knight),
+		VillagerArchetype::Minion(Minion::Minion),
+		VillagerArchetype::Demon(Demon::Lilis),
+	];
+
+	let log = log::logger();
+	let mut simulation = Simulation::deal(deck.clone(), DrawStats::new(4, 0, 1, 1), 42).unwrap();
+	let serialized_record = serde_json::to_string(simulation.record()).unwrap();
+	let outcome = simulation.play(&log, RevealStrategy::Simple).unwrap();
+
+	let record: SimulationRecord = serde_json::from_str(&serialized_record).unwrap();
+	let mut replayed_simulation = record.replay().unwrap();
+	assert_eq!(
+		outcome,
+		replayed_simulation
+			.play(&log, RevealStrategy::Simple)
+			.unwrap()
+	);
+	// the testimonies given during play are the same too
+	assert_eq!(simulation.truth(), replayed_simulation.truth());
+
+	let mut tampered_record = record;
+	tampered_record.truth.description.push_str(" (edited)");
+	assert!(matches!(
+		tampered_record.replay(),
+		Err(SimulationError::SeedMismatch(42))
+	));
+}
+
+#[test]
+fn test_corrupted_single_claim_reveals() {
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Knight),
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::Outcast(Outcast::Wretch),
+		VillagerArchetype::Minion(Minion::Minion),
+	];
+
+	let log = log::logger();
+	let index_of = |simulation: &Simulation, archetype: VillagerArchetype| {
+		simulation
+			.truth()
+			.villagers
+			.iter()
+			.position(|theoretical| *theoretical.inner.true_identity() == archetype)
+			.map(VillagerIndex)
+	};
+	let (mut simulation, knight, wretch) = (0..)
+		.find_map(|seed| {
+			let simulation =
+				Simulation::deal(deck.clone(), DrawStats::new(5, 1, 1, 0), seed).unwrap();
+			let knight = index_of(
+				&simulation,
+				VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			)?;
+			let wretch = index_of(&simulation, VillagerArchetype::Outcast(Outcast::Wretch))?;
+			Some((simulation, knight, wretch))
+		})
+		.unwrap();
+
+	// a corrupted knight's only claim is already false for the ground truth
+	simulation.truth.villagers[knight.0]
+		.inner
+		.set_corrupted(true);
+	let Action::TryReveal(reveal_result) = simulation
+		.answer(&log, &PlayerAction::TryReveal(knight.clone()))
+		.unwrap()
+	else {
+		panic!("A reveal should be answered with a reveal");
+	};
+	let testimony = reveal_result
+		.instance()
+		.as_ref()
+		.and_then(|instance| instance.testimony().clone())
+		.unwrap();
+	assert_eq!(
+		Expression::Leaf(Testimony::Invincible(knight.clone())),
+		testimony
+	);
+	assert!(!simulation.testimony_holds(&log, &knight, &testimony));
+
+	// a wretch's only claim stays true, so a lying one has nothing to show
+	simulation.truth.villagers[wretch.0]
+		.inner
+		.set_corrupted(true);
+	assert!(matches!(
+		simulation.answer(&log, &PlayerAction::TryReveal(wretch.clone())),
+		Err(SimulationError::NoConsistentTestimony(index)) if index == wretch
+	));
+}
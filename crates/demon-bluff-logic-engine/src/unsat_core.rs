@@ -0,0 +1,186 @@
+use std::{
+	collections::{BTreeSet, HashSet},
+	fmt::Display,
+};
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{DrawStats, GameState},
+	villager::VillagerArchetype,
+};
+use log::Log;
+use serde::Serialize;
+use tracy_client::span;
+
+use crate::{
+	RolesInPlay,
+	build_board_layouts::BoardLayout,
+	build_expression_for_villager_set::{IndexTestimony, build_expression_for_villager_set},
+	expression_assertion::collect_satisfying_assignments,
+	first_invalid_testimony,
+	optimized_expression::OptimizedExpression,
+};
+
+/// A minimal set of testimonies that can't all hold in any layout the deck and [`DrawStats`] allow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnsatCore {
+	/// Dropping any one of these lets at least one layout survive.
+	/// Empty if the conflict isn't down to the testimonies, such as the deck and draw stats ruling out every layout
+	pub testimonies: BTreeSet<IndexTestimony>,
+	/// The game's whole deck and draw the testimonies were checked against. These aren't narrowed down to the part that conflicts
+	pub game_deck: Vec<VillagerArchetype>,
+	pub game_draw_stats: DrawStats,
+}
+
+impl Display for UnsatCore {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.testimonies.is_empty() {
+			write!(f, "No layout fits the deck and draw stats")?;
+		} else {
+			write!(f, "Conflicting testimonies: ")?;
+			let mut first = true;
+			for index_testimony in &self.testimonies {
+				if first {
+					first = false;
+				} else {
+					write!(f, "; ")?;
+				}
+
+				write!(f, "{}", index_testimony)?;
+			}
+		}
+
+		write!(
+			f,
+			" (Game draw: {} villagers, {} outcasts, {} minions, {} demons. Game deck: {})",
+			self.game_draw_stats.villagers(),
+			self.game_draw_stats.outcasts(),
+			self.game_draw_stats.minions(),
+			self.game_draw_stats.demons(),
+			self.game_deck
+				.iter()
+				.map(|archetype| archetype.to_string())
+				.collect::<Vec<String>>()
+				.join(", ")
+		)
+	}
+}
+
+/// Shrinks the testimonies of layouts that all failed validation down to a minimal conflicting set.
+/// Leaving a testimony out of the core means it is no longer validated against the layouts
+pub fn unsat_core<'a>(
+	log: &impl Log,
+	game_state: &GameState,
+	layouts: impl IntoIterator<Item = &'a BoardLayout>,
+) -> UnsatCore {
+	let zone = span!("Unsat Core");
+	let mut core = UnsatCore {
+		testimonies: BTreeSet::new(),
+		game_deck: game_state.deck().clone(),
+		game_draw_stats: game_state.draw_stats().clone(),
+	};
+
+	let layouts: Vec<&BoardLayout> = layouts.into_iter().collect();
+	let expressions: Vec<Expression<IndexTestimony>> = layouts
+		.iter()
+		.filter_map(|layout| {
+			build_expression_for_villager_set(
+				layout
+					.villagers
+					.iter()
+					.map(|theoretical| &theoretical.inner),
+			)
+		})
+		.collect();
+	if expressions.len() != layouts.len() {
+		// a layout without testimonies can't have been contradicted by them
+		return core;
+	}
+
+	let mut master_expressions = expressions.clone();
+	let master_expression = match master_expressions.len() {
+		0 => return core,
+		1 => master_expressions.pop().unwrap(),
+		_ => Expression::MajorOr(master_expressions),
+	};
+	let optimized_master_expression = OptimizedExpression::new(&master_expression);
+	let master_variables = optimized_master_expression.variables();
+	let master_assignments = collect_satisfying_assignments(&optimized_master_expression);
+
+	let roles_in_play = RolesInPlay::new(game_state);
+
+	// for every way a layout could otherwise be satisfied, the master variables it contradicts
+	let mut contradictions: HashSet<Vec<usize>> = HashSet::new();
+	for (layout, expression) in layouts.iter().zip(expressions.iter()) {
+		let optimized_expression = OptimizedExpression::new(expression);
+		let master_variable_indicies: Vec<usize> = optimized_expression
+			.variables()
+			.iter()
+			.map(|variable| {
+				master_variables
+					.iter()
+					.position(|master_variable| master_variable == variable)
+					.expect("Layout testimony missing from the master expression")
+			})
+			.collect();
+
+		for master_assignment in &master_assignments {
+			let assignment: Vec<bool> = master_variable_indicies
+				.iter()
+				.map(|master_variable_index| master_assignment[*master_variable_index])
+				.collect();
+			if !optimized_expression.satisfies(|variable_index| assignment[variable_index]) {
+				continue;
+			}
+
+			let contradicted: Vec<usize> = (0..assignment.len())
+				.filter(|variable_index| {
+					first_invalid_testimony(
+						log,
+						&vec![assignment[*variable_index]],
+						&optimized_expression.variables()[*variable_index..=*variable_index],
+						layout,
+						game_state,
+						roles_in_play,
+					)
+					.is_some()
+				})
+				.map(|variable_index| master_variable_indicies[variable_index])
+				.collect();
+
+			if contradicted.is_empty() {
+				// this layout holds up against every testimony, so they aren't what conflicts
+				return core;
+			}
+
+			contradictions.insert(contradicted);
+		}
+	}
+
+	// deletion based shrinking, a testimony stays only if some layout would survive without it
+	let mut in_core = vec![true; master_variables.len()];
+	for variable_index in 0..master_variables.len() {
+		in_core[variable_index] = false;
+		let still_unsatisfiable = contradictions.iter().all(|contradicted| {
+			contradicted
+				.iter()
+				.any(|contradicted_index| in_core[*contradicted_index])
+		});
+		if !still_unsatisfiable {
+			in_core[variable_index] = true;
+		}
+	}
+
+	core.testimonies = master_variables
+		.iter()
+		.zip(in_core)
+		.filter_map(|(variable, in_core)| {
+			if in_core {
+				Some(variable.clone())
+			} else {
+				None
+			}
+		})
+		.collect();
+	core
+}
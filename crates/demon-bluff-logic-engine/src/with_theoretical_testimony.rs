@@ -1,9 +1,15 @@
-use std::collections::{BTreeSet, HashMap, hash_map::Entry};
+use std::{
+	collections::{BTreeSet, HashMap, hash_map::Entry},
+	num::NonZeroUsize,
+};
 
 use demon_bluff_gameplay_engine::{
 	Expression,
 	game_state::{self, GameState},
-	testimony::{DreamerClaim, DruidClaim, FortuneTellerClaim, SlayResult, Testimony},
+	testimony::{
+		ALCHEMIST_CURE_RANGE, BishopClaim, BishopEvil, DreamerClaim, DruidClaim, EvilPairsClaim,
+		FortuneTellerClaim, RoleClaim, SlayResult, Testimony, index_offset,
+	},
 	villager::{GoodVillager, Outcast, VillagerArchetype, VillagerIndex},
 };
 use itertools::Itertools;
@@ -11,7 +17,8 @@ use log::Log;
 use tracy_client::span;
 
 use crate::{
-	build_board_layouts::BoardLayout,
+	RolesInPlay,
+	build_board_layouts::{BoardLayout, TheoreticalVillager},
 	build_expression_for_villager_set::{IndexTestimony, build_expression_for_villager_set},
 	optimized_expression::OptimizedExpression,
 	player_action::AbilityAttempt,
@@ -62,6 +69,35 @@ pub fn with_theoretical_testimony(
 	log: &impl Log,
 	game_state: &GameState,
 	board_configs_and_satisfying_assignments: &Vec<LayoutWithTestimonyAssigments>,
+) -> AbilityPrediction {
+	with_theoretical_testimony_core(
+		log,
+		game_state,
+		board_configs_and_satisfying_assignments,
+		None,
+	)
+}
+
+/// Like [`with_theoretical_testimony`], but theorizes the ability of a specific villager instead of the first one available
+pub fn with_theoretical_testimony_for_villager(
+	log: &impl Log,
+	game_state: &GameState,
+	board_configs_and_satisfying_assignments: &Vec<LayoutWithTestimonyAssigments>,
+	testifier_index: &VillagerIndex,
+) -> AbilityPrediction {
+	with_theoretical_testimony_core(
+		log,
+		game_state,
+		board_configs_and_satisfying_assignments,
+		Some(testifier_index),
+	)
+}
+
+fn with_theoretical_testimony_core(
+	log: &impl Log,
+	game_state: &GameState,
+	board_configs_and_satisfying_assignments: &Vec<LayoutWithTestimonyAssigments>,
+	testifier_index: Option<&VillagerIndex>,
 ) -> AbilityPrediction {
 	let zone = span!("With Theoretical Testimony");
 	let mut results: HashMap<AbilityAttempt, Vec<PostAbilityBoardMutation>> = HashMap::new();
@@ -71,9 +107,10 @@ pub fn with_theoretical_testimony(
 			.iter()
 			.map(|layout_with_testimony_assigments| {
 				let local_results: Vec<(AbilityAttempt, LayoutWithTestimonyAssigments)> =
-					generate_theoreticals_for_first_villager_with_ability(
+					generate_theoreticals_for_villager_with_ability(
 						game_state,
 						layout_with_testimony_assigments,
+						testifier_index,
 					)
 					.collect();
 				(&layout_with_testimony_assigments.layout, local_results)
@@ -129,12 +166,7 @@ pub fn with_theoretical_testimony(
 	}
 
 	let final_results = if any_potential_testimonies_remaining {
-		let wretch_in_play = game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Wretch));
-		let drunk_in_play = game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Drunk));
-		let knight_in_play =
-			game_state.role_in_play(VillagerArchetype::GoodVillager(GoodVillager::Knight));
-		let bombardier_in_play =
-			game_state.role_in_play(VillagerArchetype::Outcast(Outcast::Bombardier));
+		let roles_in_play = RolesInPlay::new(game_state);
 		let mut expanded_results: HashMap<AbilityAttempt, Vec<PostAbilityBoardMutation>> =
 			HashMap::new();
 		for (ability_attempt, original_mutations) in results {
@@ -170,10 +202,7 @@ pub fn with_theoretical_testimony(
 									optimized_expression.variables(),
 									&new_layout.layout,
 									game_state,
-									wretch_in_play,
-									drunk_in_play,
-									knight_in_play,
-									bombardier_in_play,
+									roles_in_play,
 								)
 							});
 
@@ -222,9 +251,10 @@ pub fn with_theoretical_testimony(
 	}
 }
 
-gen fn generate_theoreticals_for_first_villager_with_ability(
+gen fn generate_theoreticals_for_villager_with_ability(
 	game_state: &GameState,
 	original_layout_with_testimonies: &LayoutWithTestimonyAssigments,
+	testifier_index: Option<&VillagerIndex>,
 ) -> (AbilityAttempt, LayoutWithTestimonyAssigments) {
 	for (index, theoretical) in original_layout_with_testimonies
 		.layout
@@ -232,7 +262,8 @@ gen fn generate_theoreticals_for_first_villager_with_ability(
 		.iter()
 		.enumerate()
 	{
-		if theoretical.revealed
+		if testifier_index.is_none_or(|testifier_index| testifier_index.0 == index)
+			&& theoretical.revealed
 			&& let None = theoretical.inner.instance().testimony()
 		{
 			for (board_layout, ability_attempt, generated_testimonies) in theoretical_testimonies(
@@ -274,7 +305,7 @@ gen fn generate_theoreticals_for_first_villager_with_ability(
 	}
 }
 
-gen fn theoretical_testimonies(
+pub gen fn theoretical_testimonies(
 	game_state: &GameState,
 	board_config: &BoardLayout,
 	testifier_index: VillagerIndex,
@@ -285,9 +316,134 @@ gen fn theoretical_testimonies(
 
 	match archetype {
 		VillagerArchetype::GoodVillager(good_villager) => match good_villager {
-			GoodVillager::Alchemist => todo!("Alchemist testimony generation"),
-			GoodVillager::Bard => todo!("Bard testimony generation"),
-			GoodVillager::Bishop => todo!("Bishop testimony generation"),
+			GoodVillager::Alchemist => {
+				let cure_range = alchemist_cure_range(&testifier_index, theoreticals.len());
+
+				let mut targets = BTreeSet::new();
+				targets.extend(cure_range.iter().cloned());
+				let ability_attempt = AbilityAttempt::new(testifier_index.clone(), targets);
+
+				let actual_cures = cure_range
+					.iter()
+					.filter(|cured_index| {
+						theoreticals[cured_index.0].cured_by.as_ref() == Some(&testifier_index)
+					})
+					.count();
+
+				for claimed_cures in 0..=cure_range.len() {
+					if (claimed_cures == actual_cures) == testifier.inner.will_lie() {
+						continue;
+					}
+
+					let mut next_layout = board_config.clone();
+					next_layout.description = format!(
+						"{} - {} says they cured {}{}",
+						next_layout.description,
+						testifier_index,
+						claimed_cures,
+						if testifier.inner.will_lie() {
+							" (LIE)"
+						} else {
+							""
+						}
+					);
+
+					let testimony = Testimony::Cured(claimed_cures);
+					next_layout.villagers[testifier_index.0]
+						.inner
+						.instance_mut()
+						.set_testimony(Expression::Leaf(testimony.clone()));
+
+					let testimonies = vec![IndexTestimony::new(testifier_index.clone(), testimony)];
+
+					yield (next_layout, ability_attempt.clone(), testimonies);
+				}
+			}
+			GoodVillager::Bard => {
+				let ability_attempt = AbilityAttempt::new(testifier_index.clone(), BTreeSet::new());
+
+				let actual_distance = closest_corruption_distance(&testifier_index, theoreticals);
+
+				// the furthest anything can be on a circular board
+				let max_distance = theoreticals.len() / 2;
+				for claimed_distance in
+					std::iter::once(None).chain((1..=max_distance).map(NonZeroUsize::new))
+				{
+					if (claimed_distance == actual_distance) == testifier.inner.will_lie() {
+						continue;
+					}
+
+					let mut next_layout = board_config.clone();
+					next_layout.description = format!(
+						"{} - {} says {}{}",
+						next_layout.description,
+						testifier_index,
+						match claimed_distance {
+							Some(distance) => format!("closest corruption is {} away", distance),
+							None => String::from("there is no corruption"),
+						},
+						if testifier.inner.will_lie() {
+							" (LIE)"
+						} else {
+							""
+						}
+					);
+
+					let testimony = Testimony::Bard(claimed_distance);
+					next_layout.villagers[testifier_index.0]
+						.inner
+						.instance_mut()
+						.set_testimony(Expression::Leaf(testimony.clone()));
+
+					let testimonies = vec![IndexTestimony::new(testifier_index.clone(), testimony)];
+
+					yield (next_layout, ability_attempt.clone(), testimonies);
+				}
+			}
+			GoodVillager::Bishop => {
+				for index_combo in theoreticals
+					.iter()
+					.enumerate()
+					.filter(move |(index, _)| *index != testifier_index.0)
+					.map(|(index, _)| VillagerIndex(index))
+					.combinations(3)
+				{
+					let mut targets = BTreeSet::new();
+					targets.extend(index_combo.iter().cloned());
+					let ability_attempt = AbilityAttempt::new(testifier_index.clone(), targets);
+
+					let combo: [VillagerIndex; 3] = index_combo.clone().try_into().unwrap();
+					for bishop_claim in bishop_claims(&combo) {
+						let claim_holds = bishop_claim_holds(&bishop_claim, theoreticals);
+						let valid = if testifier.inner.will_lie() {
+							!claim_holds
+						} else {
+							claim_holds && bishop_claim_is_exact(&bishop_claim, theoreticals)
+						};
+
+						if !valid {
+							continue;
+						}
+
+						let testimony = Testimony::Bishop(bishop_claim);
+
+						let mut next_layout = board_config.clone();
+						next_layout.description = format!(
+							"{} - {} says {}",
+							next_layout.description, testifier_index, testimony
+						);
+						next_layout.villagers[testifier_index.0]
+							.inner
+							.instance_mut()
+							.set_testimony(Expression::Leaf(testimony.clone()));
+
+						let testimonies =
+							vec![IndexTestimony::new(testifier_index.clone(), testimony)];
+
+						yield (next_layout, ability_attempt.clone(), testimonies);
+					}
+				}
+			}
 			GoodVillager::Dreamer => {
 				for (index, theoretical) in theoreticals.iter().enumerate() {
 					let mut targets = BTreeSet::new();
@@ -689,9 +845,180 @@ gen fn theoretical_testimonies(
 					yield (next_layout, ability_attempt.clone(), testimonies);
 				}
 			}
-			GoodVillager::Oracle => todo!("Oracle testimony generation"),
-			GoodVillager::Poet => todo!("FUCKING POET TESTIMONY GENERATION!!!"),
-			GoodVillager::Knitter => todo!("Knitter testimony generation"),
+			GoodVillager::Oracle => {
+				let in_game_archetypes: BTreeSet<VillagerArchetype> =
+					game_state.deck().iter().cloned().collect();
+
+				for index_combo in theoreticals
+					.iter()
+					.enumerate()
+					.filter(move |(index, _)| *index != testifier_index.0)
+					.map(|(index, _)| VillagerIndex(index))
+					.combinations(2)
+				{
+					let mut targets = BTreeSet::new();
+					targets.extend(index_combo.iter().cloned());
+					let ability_attempt = AbilityAttempt::new(testifier_index.clone(), targets);
+
+					let combo: [VillagerIndex; 2] = index_combo.clone().try_into().unwrap();
+
+					let claimable_archetypes: BTreeSet<VillagerArchetype> =
+						if testifier.inner.will_lie() {
+							in_game_archetypes
+								.iter()
+								.filter(|archetype| {
+									combo.iter().all(|target_index| {
+										!role_claim_holds(&theoreticals[target_index.0], archetype)
+									})
+								})
+								.cloned()
+								.collect()
+						} else {
+							// an unrevealed good could be anything, only theorize about what we know
+							combo
+								.iter()
+								.map(|target_index| &theoreticals[target_index.0])
+								.filter(|target| !target.unknown_unrevealed_good())
+								.map(|target| target.inner.true_identity().clone())
+								.collect()
+						};
+
+					for archetype in claimable_archetypes {
+						let mut next_layout = board_config.clone();
+						next_layout.description = format!(
+							"{} - {} says one of {} or {} is a {}{}",
+							next_layout.description,
+							testifier_index,
+							combo[0],
+							combo[1],
+							archetype,
+							if testifier.inner.will_lie() {
+								" (LIE)"
+							} else {
+								""
+							}
+						);
+
+						next_layout.villagers[testifier_index.0]
+							.inner
+							.instance_mut()
+							.set_testimony(Testimony::oracle(&combo, archetype.clone()));
+
+						let testimonies = combo
+							.iter()
+							.map(|target_index| {
+								IndexTestimony::new(
+									testifier_index.clone(),
+									Testimony::Role(RoleClaim::new(
+										target_index.clone(),
+										archetype.clone(),
+									)),
+								)
+							})
+							.collect();
+
+						yield (next_layout, ability_attempt.clone(), testimonies);
+					}
+				}
+			}
+			GoodVillager::Poet => {
+				let in_game_archetypes: BTreeSet<VillagerArchetype> =
+					game_state.deck().iter().cloned().collect();
+
+				for (index, target) in theoreticals
+					.iter()
+					.enumerate()
+					.filter(move |(index, _)| *index != testifier_index.0)
+				{
+					let target_index = VillagerIndex(index);
+
+					let mut targets = BTreeSet::new();
+					targets.insert(target_index.clone());
+					let ability_attempt = AbilityAttempt::new(testifier_index.clone(), targets);
+
+					let claimable_archetypes: Vec<VillagerArchetype> = if testifier.inner.will_lie()
+					{
+						in_game_archetypes
+							.iter()
+							.filter(|archetype| !role_claim_holds(target, archetype))
+							.cloned()
+							.collect()
+					} else if target.unknown_unrevealed_good() {
+						continue;
+					} else {
+						vec![target.inner.true_identity().clone()]
+					};
+
+					for archetype in claimable_archetypes {
+						let mut next_layout = board_config.clone();
+						next_layout.description = format!(
+							"{} - {} says {} is a {}{}",
+							next_layout.description,
+							testifier_index,
+							target_index,
+							archetype,
+							if testifier.inner.will_lie() {
+								" (LIE)"
+							} else {
+								""
+							}
+						);
+
+						let expression = Testimony::poet(&target_index, archetype);
+						let testimonies = match &expression {
+							Expression::Leaf(testimony) => {
+								vec![IndexTestimony::new(
+									testifier_index.clone(),
+									testimony.clone(),
+								)]
+							}
+							_ => unreachable!("A poet testimony should be a single role claim"),
+						};
+
+						next_layout.villagers[testifier_index.0]
+							.inner
+							.instance_mut()
+							.set_testimony(expression);
+
+						yield (next_layout, ability_attempt.clone(), testimonies);
+					}
+				}
+			}
+			GoodVillager::Knitter => {
+				let ability_attempt = AbilityAttempt::new(testifier_index.clone(), BTreeSet::new());
+
+				let (definite_pairs, potential_pairs) = evil_pairs(theoreticals);
+				for claimed_pairs in 0..=game_state.total_evils() {
+					let claim_holds = claimed_pairs >= definite_pairs
+						&& claimed_pairs <= definite_pairs + potential_pairs;
+					if claim_holds == testifier.inner.will_lie() {
+						continue;
+					}
+
+					let mut next_layout = board_config.clone();
+					next_layout.description = format!(
+						"{} - {} says there are {} pair(s) of evils{}",
+						next_layout.description,
+						testifier_index,
+						claimed_pairs,
+						if testifier.inner.will_lie() {
+							" (LIE)"
+						} else {
+							""
+						}
+					);
+
+					let testimony = Testimony::Knitter(EvilPairsClaim::new(claimed_pairs));
+					next_layout.villagers[testifier_index.0]
+						.inner
+						.instance_mut()
+						.set_testimony(Expression::Leaf(testimony.clone()));
+
+					let testimonies = vec![IndexTestimony::new(testifier_index.clone(), testimony)];
+
+					yield (next_layout, ability_attempt.clone(), testimonies);
+				}
+			}
 			GoodVillager::Architect
 			| GoodVillager::Baker
 			| GoodVillager::Confessor
@@ -842,3 +1169,366 @@ fn fortune_teller_expression(indexes: &Vec<VillagerIndex>) -> [(Expression<Testi
 		),
 	]
 }
+
+fn alchemist_cure_range(
+	alchemist_index: &VillagerIndex,
+	total_villagers: usize,
+) -> BTreeSet<VillagerIndex> {
+	let mut cure_range = BTreeSet::new();
+	for distance in 1..=ALCHEMIST_CURE_RANGE {
+		cure_range.insert(index_offset(
+			alchemist_index,
+			total_villagers,
+			distance,
+			true,
+		));
+		cure_range.insert(index_offset(
+			alchemist_index,
+			total_villagers,
+			distance,
+			false,
+		));
+	}
+
+	cure_range.remove(alchemist_index);
+	cure_range
+}
+
+fn closest_corruption_distance(
+	bard_index: &VillagerIndex,
+	theoreticals: &[TheoreticalVillager],
+) -> Option<NonZeroUsize> {
+	for distance in 1..=(theoreticals.len() / 2) {
+		let clockwise_read = index_offset(bard_index, theoreticals.len(), distance, true);
+		let counterclockwise_read = index_offset(bard_index, theoreticals.len(), distance, false);
+
+		if theoreticals[clockwise_read.0].inner.corrupted()
+			|| theoreticals[counterclockwise_read.0].inner.corrupted()
+		{
+			return NonZeroUsize::new(distance);
+		}
+	}
+
+	None
+}
+
+/// Returns the number of adjacent evil pairs we know of and the number of additional ones unknown villagers could form
+fn evil_pairs(theoreticals: &[TheoreticalVillager]) -> (usize, usize) {
+	let mut definite_pairs = 0;
+	let mut potential_pairs = 0;
+	for i in 0..theoreticals.len() {
+		let left_theoretical = &theoreticals[i];
+		let right_theoretical = &theoreticals[(i + 1) % theoreticals.len()];
+
+		let left_appears_evil = left_theoretical.inner.true_identity().appears_evil();
+		let right_appears_evil = right_theoretical.inner.true_identity().appears_evil();
+		if left_appears_evil && right_appears_evil {
+			definite_pairs += 1;
+		} else if (left_appears_evil || left_theoretical.unknown_unrevealed_good())
+			&& (right_appears_evil || right_theoretical.unknown_unrevealed_good())
+		{
+			potential_pairs += 1;
+		}
+	}
+
+	(definite_pairs, potential_pairs)
+}
+
+fn role_claim_holds(target: &TheoreticalVillager, archetype: &VillagerArchetype) -> bool {
+	let true_identity = target.inner.true_identity();
+	true_identity == archetype
+		// wretches show up as minions
+		|| (matches!(archetype, VillagerArchetype::Minion(_))
+			&& true_identity.appears_evil()
+			&& !true_identity.is_evil())
+}
+
+fn bishop_claims(targets: &[VillagerIndex; 3]) -> Vec<BishopClaim> {
+	let mut claims = Vec::new();
+	for good_villager in [true, false] {
+		for outcast in [true, false] {
+			for evil in [None, Some(BishopEvil::Minion), Some(BishopEvil::Demon)] {
+				claims.push(BishopClaim::new(targets, good_villager, outcast, evil));
+			}
+		}
+	}
+
+	claims
+}
+
+fn bishop_target_categories(
+	bishop_claim: &BishopClaim,
+	theoreticals: &[TheoreticalVillager],
+) -> (bool, bool, bool, bool) {
+	let mut found_good = false;
+	let mut found_outcast = false;
+	let mut found_minion = false;
+	let mut found_demon = false;
+	for target_index in bishop_claim.targets() {
+		match theoreticals[target_index.0].inner.true_identity() {
+			VillagerArchetype::GoodVillager(_) => found_good = true,
+			VillagerArchetype::Outcast(_) => found_outcast = true,
+			VillagerArchetype::Minion(_) => found_minion = true,
+			VillagerArchetype::Demon(_) => found_demon = true,
+		}
+	}
+
+	(found_good, found_outcast, found_minion, found_demon)
+}
+
+fn bishop_claim_holds(bishop_claim: &BishopClaim, theoreticals: &[TheoreticalVillager]) -> bool {
+	let (found_good, found_outcast, found_minion, found_demon) =
+		bishop_target_categories(bishop_claim, theoreticals);
+
+	(!bishop_claim.good_villager() || found_good)
+		&& (!bishop_claim.outcast() || found_outcast)
+		&& match bishop_claim.evil() {
+			Some(BishopEvil::Minion) => found_minion,
+			Some(BishopEvil::Demon) => found_demon,
+			None => true,
+		}
+}
+
+/// A truthful bishop reports everything they see, not just a subset of it
+fn bishop_claim_is_exact(bishop_claim: &BishopClaim, theoreticals: &[TheoreticalVillager]) -> bool {
+	let (found_good, found_outcast, found_minion, found_demon) =
+		bishop_target_categories(bishop_claim, theoreticals);
+
+	bishop_claim.good_villager() == found_good
+		&& bishop_claim.outcast() == found_outcast
+		&& match bishop_claim.evil() {
+			Some(BishopEvil::Minion) => found_minion,
+			Some(BishopEvil::Demon) => found_demon,
+			None => !found_minion && !found_demon,
+		}
+}
+
+#[cfg(test)]
+mod tests {
+	use demon_bluff_gameplay_engine::{
+		game_state::{DrawStats, new_game},
+		villager::{Demon, Minion},
+	};
+
+	use super::*;
+
+	// #1 is the villager whose testimonies are generated. #3 is the Minion, #4 the Baa, #5 is corrupted and #6 is the Drunk
+	fn test_board(testifier: GoodVillager, corrupted_testifier: bool) -> (GameState, BoardLayout) {
+		let game_state = new_game(
+			vec![
+				VillagerArchetype::GoodVillager(GoodVillager::Alchemist),
+				VillagerArchetype::GoodVillager(GoodVillager::Bard),
+				VillagerArchetype::GoodVillager(GoodVillager::Bishop),
+				VillagerArchetype::GoodVillager(GoodVillager::Knitter),
+				VillagerArchetype::GoodVillager(GoodVillager::Oracle),
+				VillagerArchetype::GoodVillager(GoodVillager::Poet),
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				VillagerArchetype::Outcast(Outcast::Drunk),
+				VillagerArchetype::Minion(Minion::Minion),
+				VillagerArchetype::Demon(Demon::Baa),
+			],
+			DrawStats::new(4, 1, 1, 1),
+			2,
+			false,
+		)
+		.expect("Invalid deck");
+
+		let confessor = VillagerArchetype::GoodVillager(GoodVillager::Confessor);
+		let board_layout = BoardLayout::revealed([
+			(
+				VillagerArchetype::GoodVillager(testifier),
+				None,
+				corrupted_testifier,
+			),
+			(confessor.clone(), None, false),
+			(
+				confessor.clone(),
+				Some(VillagerArchetype::Minion(Minion::Minion)),
+				false,
+			),
+			(
+				confessor.clone(),
+				Some(VillagerArchetype::Demon(Demon::Baa)),
+				false,
+			),
+			(confessor.clone(), None, true),
+			(VillagerArchetype::Outcast(Outcast::Drunk), None, true),
+			(confessor, None, false),
+		]);
+
+		(game_state, board_layout)
+	}
+
+	fn generated_testimonies(
+		game_state: &GameState,
+		board_layout: &BoardLayout,
+	) -> Vec<Expression<Testimony>> {
+		theoretical_testimonies(game_state, board_layout, VillagerIndex(0))
+			.map(|(next_layout, _, _)| {
+				next_layout.villagers[0]
+					.inner
+					.instance()
+					.testimony()
+					.clone()
+					.expect("Generated layouts should have a testimony")
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_alchemist_theoreticals() {
+		let (game_state, mut board_layout) = test_board(GoodVillager::Alchemist, false);
+		board_layout.villagers[1].cured_by = Some(VillagerIndex(0));
+
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![Expression::Leaf(Testimony::Cured(1))]
+		);
+
+		let (game_state, mut board_layout) = test_board(GoodVillager::Alchemist, true);
+		board_layout.villagers[1].cured_by = Some(VillagerIndex(0));
+
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![
+				Expression::Leaf(Testimony::Cured(0)),
+				Expression::Leaf(Testimony::Cured(2)),
+				Expression::Leaf(Testimony::Cured(3)),
+				Expression::Leaf(Testimony::Cured(4)),
+			]
+		);
+	}
+
+	#[test]
+	fn test_bard_theoreticals() {
+		let (game_state, board_layout) = test_board(GoodVillager::Bard, false);
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![Expression::Leaf(Testimony::Bard(NonZeroUsize::new(2)))]
+		);
+
+		let (game_state, board_layout) = test_board(GoodVillager::Bard, true);
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![
+				Expression::Leaf(Testimony::Bard(None)),
+				Expression::Leaf(Testimony::Bard(NonZeroUsize::new(1))),
+				Expression::Leaf(Testimony::Bard(NonZeroUsize::new(3))),
+			]
+		);
+	}
+
+	#[test]
+	fn test_bishop_theoreticals() {
+		let (game_state, board_layout) = test_board(GoodVillager::Bishop, false);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+
+		// one exact claim per combination of three others, two when both evils are seen
+		assert_eq!(testimonies.len(), 24);
+		assert!(
+			testimonies.contains(&Expression::Leaf(Testimony::Bishop(BishopClaim::new(
+				&[VillagerIndex(1), VillagerIndex(2), VillagerIndex(5)],
+				true,
+				true,
+				Some(BishopEvil::Minion),
+			))))
+		);
+		assert!(
+			!testimonies.contains(&Expression::Leaf(Testimony::Bishop(BishopClaim::new(
+				&[VillagerIndex(1), VillagerIndex(2), VillagerIndex(5)],
+				true,
+				false,
+				Some(BishopEvil::Minion),
+			))))
+		);
+
+		let (game_state, board_layout) = test_board(GoodVillager::Bishop, true);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+		assert!(
+			!testimonies.contains(&Expression::Leaf(Testimony::Bishop(BishopClaim::new(
+				&[VillagerIndex(1), VillagerIndex(2), VillagerIndex(5)],
+				true,
+				false,
+				Some(BishopEvil::Minion),
+			))))
+		);
+		assert!(
+			testimonies.contains(&Expression::Leaf(Testimony::Bishop(BishopClaim::new(
+				&[VillagerIndex(1), VillagerIndex(2), VillagerIndex(5)],
+				false,
+				false,
+				Some(BishopEvil::Demon),
+			))))
+		);
+	}
+
+	#[test]
+	fn test_oracle_theoreticals() {
+		let (game_state, board_layout) = test_board(GoodVillager::Oracle, false);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+		assert!(testimonies.contains(&Testimony::oracle(
+			&[VillagerIndex(1), VillagerIndex(2)],
+			VillagerArchetype::Minion(Minion::Minion),
+		)));
+		assert!(!testimonies.contains(&Testimony::oracle(
+			&[VillagerIndex(1), VillagerIndex(5)],
+			VillagerArchetype::Minion(Minion::Minion),
+		)));
+		// the oracle doesn't learn about itself
+		assert!(!testimonies.contains(&Testimony::oracle(
+			&[VillagerIndex(0), VillagerIndex(2)],
+			VillagerArchetype::Minion(Minion::Minion),
+		)));
+
+		let (game_state, board_layout) = test_board(GoodVillager::Oracle, true);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+		assert!(!testimonies.contains(&Testimony::oracle(
+			&[VillagerIndex(1), VillagerIndex(2)],
+			VillagerArchetype::Minion(Minion::Minion),
+		)));
+		assert!(testimonies.contains(&Testimony::oracle(
+			&[VillagerIndex(1), VillagerIndex(5)],
+			VillagerArchetype::Minion(Minion::Minion),
+		)));
+	}
+
+	#[test]
+	fn test_poet_theoreticals() {
+		let (game_state, board_layout) = test_board(GoodVillager::Poet, false);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+		assert_eq!(testimonies.len(), 6);
+		assert!(testimonies.contains(&Testimony::poet(
+			&VillagerIndex(3),
+			VillagerArchetype::Demon(Demon::Baa)
+		)));
+
+		let (game_state, board_layout) = test_board(GoodVillager::Poet, true);
+		let testimonies = generated_testimonies(&game_state, &board_layout);
+		assert!(!testimonies.contains(&Testimony::poet(
+			&VillagerIndex(3),
+			VillagerArchetype::Demon(Demon::Baa)
+		)));
+		assert!(testimonies.contains(&Testimony::poet(
+			&VillagerIndex(1),
+			VillagerArchetype::Demon(Demon::Baa)
+		)));
+	}
+
+	#[test]
+	fn test_knitter_theoreticals() {
+		let (game_state, board_layout) = test_board(GoodVillager::Knitter, false);
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![Expression::Leaf(Testimony::Knitter(EvilPairsClaim::new(1)))]
+		);
+
+		let (game_state, board_layout) = test_board(GoodVillager::Knitter, true);
+		assert_eq!(
+			generated_testimonies(&game_state, &board_layout),
+			vec![
+				Expression::Leaf(Testimony::Knitter(EvilPairsClaim::new(0))),
+				Expression::Leaf(Testimony::Knitter(EvilPairsClaim::new(2))),
+			]
+		);
+	}
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, RevealResult, new_game},
+	testimony::{ConfessorClaim, RoleClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::{
+	IndexTestimony, PlayerAction, RevealStrategy, predict_with_explanation,
+};
+
+// game_0005 part way through
+#[test]
+fn explanation_0001() {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(5, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	let medium_claim = Testimony::Role(RoleClaim::new(
+		VillagerIndex(5),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+	));
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+				Some(Testimony::hunter(
+					&VillagerIndex(0),
+					2,
+					game_state.total_villagers(),
+				)),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Medium),
+				Some(Expression::Leaf(medium_claim.clone())),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(3),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Judge),
+				None,
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	let log = log::logger();
+	let (prediction, explanation) =
+		predict_with_explanation(&log, &game_state, RevealStrategy::Simple);
+	let actions = prediction.expect("Prediction failed!");
+
+	// the minion is the hunter, the medium or the unrevealed #5
+	let surviving_evils: BTreeSet<BTreeSet<VillagerIndex>> = explanation
+		.surviving_layouts
+		.iter()
+		.map(|layout| layout.evil_locations.clone())
+		.collect();
+	assert_eq!(
+		[VillagerIndex(0), VillagerIndex(2), VillagerIndex(4)]
+			.into_iter()
+			.map(|index| BTreeSet::from([index]))
+			.collect::<BTreeSet<_>>(),
+		surviving_evils
+	);
+
+	// the prediction only picks from villagers that are evil in a surviving layout
+	for action in &actions {
+		if let PlayerAction::TryExecute(index) = action {
+			assert!(
+				explanation
+					.surviving_layouts
+					.iter()
+					.any(|layout| layout.evil_locations.contains(index))
+			);
+		}
+	}
+
+	// a lying minion medium means #6 isn't the lover
+	let medium_lie = IndexTestimony::new(VillagerIndex(2), medium_claim);
+	for layout in &explanation.surviving_layouts {
+		assert_eq!(
+			layout.evil_locations.contains(&VillagerIndex(2)),
+			layout.lies.contains(&medium_lie),
+			"{}",
+			layout.description
+		);
+	}
+
+	assert!(!explanation.eliminated_layouts.is_empty());
+	for layout in &explanation.eliminated_layouts {
+		assert!(!layout.eliminated_by.is_empty(), "{}", layout.description);
+		assert!(!surviving_evils.contains(&layout.evil_locations));
+	}
+
+	let text = explanation.to_string();
+	assert!(text.starts_with(&format!(
+		"{} surviving layouts:",
+		explanation.surviving_layouts.len()
+	)));
+}
@@ -0,0 +1,108 @@
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{
+		AbilityResult, DrawStats, KillAttempt, KillData, KillResult, RevealResult, new_game,
+	},
+	testimony::{ConfessorClaim, RoleClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+
+use super::run_game;
+use crate::helpers::TestAction;
+
+// same board as simple_strategy::game_0005, but the judge is asked before the last reveals are spent
+#[test]
+fn game_0005() {
+	let game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(5, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	run_game(
+		&game_state,
+		vec![
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(0),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+					Some(Testimony::hunter(
+						&VillagerIndex(0),
+						2,
+						game_state.total_villagers(),
+					)),
+				)),
+			)),
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(1),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+					Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+				)),
+			)),
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(2),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Medium),
+					Some(Expression::Leaf(Testimony::Role(RoleClaim::new(
+						VillagerIndex(5),
+						VillagerArchetype::GoodVillager(GoodVillager::Lover),
+					)))),
+				)),
+			)),
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(3),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Judge),
+					None,
+				)),
+			)),
+			TestAction::Ability(
+				vec![VillagerIndex::number(1)],
+				AbilityResult::new(
+					VillagerIndex(3),
+					Some(Expression::Not(Box::new(Expression::Leaf(
+						Testimony::Lying(VillagerIndex(0)),
+					)))),
+					None,
+				),
+			),
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(4),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+					Some(Expression::Leaf(Testimony::Good(VillagerIndex(4)))),
+				)),
+			)),
+			TestAction::TryReveal(RevealResult::new(
+				VillagerIndex(5),
+				Some(VillagerInstance::new(
+					VillagerArchetype::GoodVillager(GoodVillager::Lover),
+					Some(Testimony::lover(
+						&VillagerIndex(5),
+						1,
+						game_state.total_villagers(),
+					)),
+				)),
+			)),
+			TestAction::TryExecute(KillAttempt::new(
+				VillagerIndex(4),
+				Some(KillResult::Revealed(
+					KillData::new(Some(VillagerArchetype::Minion(Minion::Minion)), false)
+						.expect("Bad kill data"),
+				)),
+			)),
+		],
+		None,
+	);
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, GameState, GameStateMutationResult},
+	testimony::Testimony,
+	villager::{
+		ActiveVillager, Demon, GoodVillager, HiddenVillager, Minion, Villager, VillagerArchetype,
+		VillagerIndex, VillagerInstance,
+	},
+};
+use demon_bluff_logic_engine::{PlayerAction, RevealStrategy, predict};
+
+fn night_state(deck: Vec<VillagerArchetype>, draw_stats: DrawStats, next_day: u8) -> GameState {
+	GameState::new(
+		Some(next_day),
+		draw_stats,
+		deck,
+		vec![
+			Villager::Active(ActiveVillager::new(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+				Some(Expression::Leaf(Testimony::Good(VillagerIndex(1)))),
+			))),
+			Villager::Hidden(HiddenVillager::new(false, false, false)),
+			Villager::Hidden(HiddenVillager::new(false, false, false)),
+			Villager::Hidden(HiddenVillager::new(false, false, false)),
+		],
+		vec![VillagerIndex(0)],
+		10,
+		1,
+	)
+	.expect("Invalid game state")
+}
+
+// the gemcrafter can't be lilis without #2 being evil too, so #2 is the villager we can afford to lose
+#[test]
+fn night_kill_0001() {
+	let deck = vec![
+		VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+		VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+		VillagerArchetype::GoodVillager(GoodVillager::Lover),
+		VillagerArchetype::GoodVillager(GoodVillager::Medium),
+		VillagerArchetype::Demon(Demon::Lilis),
+	];
+	let draw_stats = DrawStats::new(3, 0, 0, 1);
+	let log = log::logger();
+
+	let day_state = night_state(deck.clone(), draw_stats.clone(), 4);
+	assert!(!day_state.must_take_night_action());
+	let day_actions = predict(&log, &day_state, RevealStrategy::Simple).unwrap();
+	assert!(
+		day_actions
+			.iter()
+			.all(|action| !matches!(action, PlayerAction::LilisNightKill(_)))
+	);
+
+	let mut state = night_state(deck, draw_stats, 5);
+	assert!(state.must_take_night_action());
+	let actions = predict(&log, &state, RevealStrategy::Simple).unwrap();
+	assert_eq!(
+		HashSet::from([PlayerAction::LilisNightKill(Some(VillagerIndex(1)))]),
+		actions
+	);
+
+	assert_eq!(
+		GameStateMutationResult::Continue,
+		state
+			.mutate(Action::LilisNightKill(Some(VillagerIndex(1))))
+			.unwrap()
+	);
+}
+
+#[test]
+fn night_kill_without_lilis() {
+	let state = night_state(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(3, 0, 1, 0),
+		5,
+	);
+
+	let log = log::logger();
+	assert_eq!(
+		HashSet::from([PlayerAction::LilisNightKill(None)]),
+		predict(&log, &state, RevealStrategy::Simple).unwrap()
+	);
+}
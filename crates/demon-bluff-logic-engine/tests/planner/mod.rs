@@ -0,0 +1,106 @@
+use std::collections::BTreeSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, RevealResult, new_game},
+	testimony::{ConfessorClaim, RoleClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::{AbilityAttempt, PlayerAction, rank_actions};
+
+// game_0005 with the judge revealed while hidden villagers remain
+#[test]
+fn planner_0001() {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(5, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+				Some(Testimony::hunter(
+					&VillagerIndex(0),
+					2,
+					game_state.total_villagers(),
+				)),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Medium),
+				Some(Expression::Leaf(Testimony::Role(RoleClaim::new(
+					VillagerIndex(5),
+					VillagerArchetype::GoodVillager(GoodVillager::Lover),
+				)))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(3),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Judge),
+				None,
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	let log = log::logger();
+	let ranked_actions = rank_actions(&log, &game_state).expect("Failed ranking!");
+
+	assert!(
+		ranked_actions
+			.windows(2)
+			.all(|pair| pair[0].score >= pair[1].score),
+		"Actions should be ranked best first"
+	);
+
+	let gain_of = |action: &PlayerAction| {
+		ranked_actions
+			.iter()
+			.find(|ranked_action| ranked_action.action == *action)
+			.unwrap_or_else(|| panic!("{} was not ranked", action))
+			.expected_information_gain
+	};
+
+	let mut targets = BTreeSet::new();
+	targets.insert(VillagerIndex(0));
+	let judge_hunter = PlayerAction::Ability(AbilityAttempt::new(VillagerIndex(3), targets));
+
+	// asking the judge about the hunter narrows the evils down further than spending one of the last reveals
+	assert_eq!(
+		ranked_actions[0].expected_information_gain,
+		gain_of(&judge_hunter)
+	);
+	assert!(gain_of(&judge_hunter) > gain_of(&PlayerAction::TryReveal(VillagerIndex(5))));
+
+	let execution_of_5 = ranked_actions
+		.iter()
+		.find(|ranked_action| ranked_action.action == PlayerAction::TryExecute(VillagerIndex(5)))
+		.expect("Execution was not ranked");
+	assert!(execution_of_5.score < execution_of_5.expected_information_gain);
+}
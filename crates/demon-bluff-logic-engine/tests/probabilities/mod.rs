@@ -0,0 +1,105 @@
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, RevealResult, new_game},
+	testimony::{ConfessorClaim, RoleClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::villager_probabilities;
+
+// game_0005 part way through
+#[test]
+fn probabilities_0001() {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(5, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+				Some(Testimony::hunter(
+					&VillagerIndex(0),
+					2,
+					game_state.total_villagers(),
+				)),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Medium),
+				Some(Expression::Leaf(Testimony::Role(RoleClaim::new(
+					VillagerIndex(5),
+					VillagerArchetype::GoodVillager(GoodVillager::Lover),
+				)))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(3),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Judge),
+				None,
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	let log = log::logger();
+	let probabilities = villager_probabilities(&log, &game_state).expect("Failed probabilities!");
+	assert_eq!(game_state.total_villagers(), probabilities.len());
+
+	// the minion is equally likely to be the hunter, the medium or the unrevealed #5
+	for index in [VillagerIndex(0), VillagerIndex(2), VillagerIndex(4)] {
+		let villager_probabilities = &probabilities[&index];
+		assert!((villager_probabilities.evil - 1.0 / 3.0).abs() < f64::EPSILON);
+		assert!(
+			(villager_probabilities.execution_costs_hitpoints - 2.0 / 3.0).abs() < f64::EPSILON
+		);
+	}
+
+	let medium_probabilities = &probabilities[&VillagerIndex(2)];
+	assert_eq!(medium_probabilities.lying, medium_probabilities.evil);
+	assert!(
+		(medium_probabilities.archetypes[&VillagerArchetype::GoodVillager(GoodVillager::Medium)]
+			- 2.0 / 3.0)
+			.abs() < f64::EPSILON
+	);
+
+	let judge_probabilities = &probabilities[&VillagerIndex(3)];
+	assert_eq!(0.0, judge_probabilities.evil);
+	assert_eq!(1.0, judge_probabilities.execution_costs_hitpoints);
+	assert_eq!(
+		Some(&1.0),
+		judge_probabilities
+			.archetypes
+			.get(&VillagerArchetype::GoodVillager(GoodVillager::Judge))
+	);
+
+	// nothing is known about #6 except that they're good
+	let unknown_probabilities = &probabilities[&VillagerIndex(5)];
+	assert_eq!(0.0, unknown_probabilities.evil);
+	assert!(unknown_probabilities.archetypes.is_empty());
+}
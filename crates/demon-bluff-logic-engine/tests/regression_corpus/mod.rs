@@ -0,0 +1,218 @@
+use std::{
+	collections::HashSet,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use demon_bluff_gameplay_engine::{
+	game_state::{GameState, GameStateMutationResult},
+	notation::Transcript,
+	replay::Replay,
+};
+use demon_bluff_logic_engine::{PlayerAction, RevealStrategy, predict};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/// A recorded game along with what [`predict`] should make of it. See [`CorpusCase::record`] for making one
+#[derive(Serialize, Deserialize)]
+pub struct CorpusCase {
+	pub reveal_strategy: RevealStrategy,
+	pub replay: Replay,
+	/// What predict should return before each step of the replay, plus after the last step if there is one more
+	pub expected_actions: Vec<Vec<PlayerAction>>,
+}
+
+impl CorpusCase {
+	/// Expects whatever is currently predicted before each step of the state's history and after the last. Write it into the [`corpus_directory`] as JSON to add it to the corpus
+	pub fn record(game_state: &GameState, reveal_strategy: RevealStrategy) -> Self {
+		let log = log::logger();
+		let replay = Replay::from_state(game_state);
+		let mut state = replay.initial_state.clone();
+		let mut expected_actions = Vec::with_capacity(replay.steps.len() + 1);
+		for step in &replay.steps {
+			expected_actions.push(predicted_actions(&log, &state, reveal_strategy));
+			state
+				.mutate(step.action.clone())
+				.expect("Game state mutation failed");
+		}
+
+		if replay
+			.steps
+			.last()
+			.is_none_or(|step| step.result == GameStateMutationResult::Continue)
+		{
+			expected_actions.push(predicted_actions(&log, &state, reveal_strategy));
+		}
+
+		Self {
+			reveal_strategy,
+			replay,
+			expected_actions,
+		}
+	}
+}
+
+pub fn corpus_directory() -> PathBuf {
+	let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+	path.push("tests");
+	path.push("game_states");
+	path
+}
+
+/// Runs every file in the corpus directory and reports all of the mismatches together.
+/// Files that are a bare [`GameState`], such as the ones `generate_state_file` writes, only have to predict without error
+#[test]
+fn regression_corpus() {
+	let directory = corpus_directory();
+	let paths: Vec<PathBuf> = fs::read_dir(&directory)
+		.unwrap_or_else(|error| {
+			panic!(
+				"Unable to read corpus directory {}: {}",
+				directory.display(),
+				error
+			)
+		})
+		.map(|entry| entry.expect("Unable to read corpus directory").path())
+		.filter(|path| {
+			path.extension()
+				.is_some_and(|extension| extension == "json")
+		})
+		.sorted()
+		.collect();
+
+	let mut failures = Vec::new();
+	for path in &paths {
+		failures.extend(
+			run_corpus_file(path)
+				.into_iter()
+				.map(|failure| format!("{}: {}", path.display(), failure)),
+		);
+	}
+
+	assert!(
+		failures.is_empty(),
+		"{} mismatches over {} corpus files:\n - {}",
+		failures.len(),
+		paths.len(),
+		failures.join("\n - ")
+	);
+}
+
+#[test]
+fn recorded_case_replays() {
+	let transcript: Transcript = "\
+Deck: Lover, Gemcrafter, Confessor, Hunter, Minion
+Draw: 4 villagers, 0 outcasts, 1 minions, 0 demons
+Evils: 1
+Night effects: false
+Reveal #1 Lover {((#2 is good) && (#5 is evil)) || ((#2 is evil) && (#5 is good))}
+Reveal #2 Confessor {I confess to being Dizzy}
+Kill #2 => Minion
+"
+	.parse()
+	.expect("Invalid transcript");
+	let (game_state, _) = transcript.replay().expect("Transcript replay failed");
+
+	let case = CorpusCase::record(&game_state, RevealStrategy::Simple);
+	assert_eq!(3, case.expected_actions.len());
+	let case: CorpusCase =
+		serde_json::from_str(&serde_json::to_string(&case).unwrap()).expect("Round trip failed");
+	assert_eq!(Vec::<String>::new(), run_case(&case));
+}
+
+fn run_corpus_file(path: &Path) -> Vec<String> {
+	let contents = match fs::read_to_string(path) {
+		Ok(contents) => contents,
+		Err(error) => return vec![format!("Unable to read file: {}", error)],
+	};
+
+	match serde_json::from_str::<CorpusCase>(&contents) {
+		Ok(case) => run_case(&case),
+		Err(case_error) => match serde_json::from_str::<GameState>(&contents) {
+			Ok(state) => match predict(&log::logger(), &state, RevealStrategy::Simple) {
+				Ok(_) => Vec::new(),
+				Err(error) => vec![format!("Prediction failed: {}", error)],
+			},
+			Err(_) => vec![format!("Not a corpus case or game state: {}", case_error)],
+		},
+	}
+}
+
+fn run_case(case: &CorpusCase) -> Vec<String> {
+	let mut failures = Vec::new();
+	let total_steps = case.replay.steps.len();
+	if case.expected_actions.len() != total_steps && case.expected_actions.len() != total_steps + 1
+	{
+		failures.push(format!(
+			"{} expected predictions for {} steps",
+			case.expected_actions.len(),
+			total_steps
+		));
+	}
+
+	let log = log::logger();
+	let mut state = case.replay.initial_state.clone();
+	for (step, expected_actions) in case.expected_actions.iter().enumerate() {
+		let expected_actions: HashSet<PlayerAction> = expected_actions.iter().cloned().collect();
+		match predict(&log, &state, case.reveal_strategy) {
+			Ok(actions) => {
+				if actions != expected_actions {
+					failures.push(format!(
+						"Step {}: expected {} but got {}",
+						step + 1,
+						format_actions(&expected_actions),
+						format_actions(&actions)
+					));
+				}
+			}
+			Err(error) => failures.push(format!("Step {}: prediction failed: {}", step + 1, error)),
+		}
+
+		let Some(replay_step) = case.replay.steps.get(step) else {
+			break;
+		};
+
+		match state.mutate(replay_step.action.clone()) {
+			Ok(result) if result == replay_step.result => {}
+			Ok(result) => {
+				failures.push(format!(
+					"Step {}: resulted in {:?} but {:?} was recorded",
+					step + 1,
+					result,
+					replay_step.result
+				));
+				break;
+			}
+			Err(error) => {
+				failures.push(format!(
+					"Step {}: could not be applied: {}",
+					step + 1,
+					error
+				));
+				break;
+			}
+		}
+	}
+
+	failures
+}
+
+fn predicted_actions(
+	log: &impl log::Log,
+	game_state: &GameState,
+	reveal_strategy: RevealStrategy,
+) -> Vec<PlayerAction> {
+	predict(log, game_state, reveal_strategy)
+		.expect("Failed prediction!")
+		.into_iter()
+		.sorted_by_key(|action| action.to_string())
+		.collect()
+}
+
+fn format_actions(actions: &HashSet<PlayerAction>) -> String {
+	actions
+		.iter()
+		.map(|action| action.to_string())
+		.sorted()
+		.join("|")
+}
@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{AbilityResult, Action, DrawStats, GameState, RevealResult, new_game},
+	rule_set::{JesterRule, RuleSet},
+	testimony::{ConfessorClaim, Testimony},
+	villager::{GoodVillager, Minion, Outcast, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::{PlayerAction, RevealStrategy, predict, villager_probabilities};
+
+// #1 the Jester says exactly one of #3 the Knight, #4 the Wretch and the unrevealed #5 is evil
+fn jester_game(rule_set: RuleSet) -> GameState {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Jester),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::Outcast(Outcast::Wretch),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(3, 1, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck")
+	.with_rule_set(rule_set);
+
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Jester),
+				None,
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Knight),
+				Some(Expression::Leaf(Testimony::Invincible(VillagerIndex(2)))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(3),
+			Some(VillagerInstance::new(
+				VillagerArchetype::Outcast(Outcast::Wretch),
+				Some(Expression::Leaf(Testimony::FakeEvil(VillagerIndex(3)))),
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	game_state
+		.mutate(Action::Ability(AbilityResult::new(
+			VillagerIndex(0),
+			Some(Testimony::jester(
+				&[VillagerIndex(2), VillagerIndex(3), VillagerIndex(4)],
+				1,
+			)),
+			None,
+		)))
+		.expect("Game state mutation failed");
+
+	game_state
+}
+
+#[test]
+fn jester_counts_the_wretch() {
+	let log = log::logger();
+
+	// the Wretch is the one evil, so a truthful Jester leaves nowhere for the Minion to be
+	let game_state = jester_game(RuleSet::current());
+	let mut expected = HashSet::new();
+	expected.insert(PlayerAction::TryExecute(VillagerIndex(0)));
+	assert_eq!(
+		expected,
+		predict(&log, &game_state, RevealStrategy::Simple).expect("Prediction failed")
+	);
+}
+
+#[test]
+fn jester_ignores_the_wretch() {
+	let log = log::logger();
+
+	// the Minion is #3 or #5 if the Jester is truthful, or the Jester itself
+	let game_state = jester_game(RuleSet {
+		jester: JesterRule::IsEvil,
+		..RuleSet::current()
+	});
+	let probabilities = villager_probabilities(&log, &game_state).expect("Failed probabilities!");
+	for (index, evil) in [
+		(0, 1.0 / 3.0),
+		(1, 0.0),
+		(2, 1.0 / 3.0),
+		(3, 0.0),
+		(4, 1.0 / 3.0),
+	] {
+		assert!((probabilities[&VillagerIndex(index)].evil - evil).abs() < f64::EPSILON);
+	}
+}
+
+// #1 the Judge says #3 the Knight is lying. The Plague Doctor can corrupt anyone
+fn judge_game(rule_set: RuleSet) -> GameState {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Knight),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::Outcast(Outcast::PlagueDoctor),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(3, 1, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck")
+	.with_rule_set(rule_set);
+
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Judge),
+				None,
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Knight),
+				Some(Expression::Leaf(Testimony::Invincible(VillagerIndex(2)))),
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	game_state
+		.mutate(Action::Ability(AbilityResult::new(
+			VillagerIndex(0),
+			Some(Expression::Leaf(Testimony::Lying(VillagerIndex(2)))),
+			None,
+		)))
+		.expect("Game state mutation failed");
+
+	game_state
+}
+
+fn assert_evil(game_state: &GameState, expected: [f64; 5]) {
+	let probabilities =
+		villager_probabilities(&log::logger(), game_state).expect("Failed probabilities!");
+	for (index, evil) in expected.into_iter().enumerate() {
+		assert!((probabilities[&VillagerIndex(index)].evil - evil).abs() < f64::EPSILON);
+	}
+}
+
+#[test]
+fn judge_sees_corruption() {
+	// the Knight can be the Minion or just corrupted by the Plague Doctor
+	assert_evil(
+		&judge_game(RuleSet::current()),
+		[3.0 / 10.0, 0.0, 3.0 / 10.0, 2.0 / 10.0, 2.0 / 10.0],
+	);
+}
+
+#[test]
+fn judge_ignores_corruption() {
+	// a Knight corrupted by the Plague Doctor isn't lying by nature, so the Judge can't have caught one and is more likely the Minion itself
+	assert_evil(
+		&judge_game(RuleSet::named("early-access").expect("Missing profile")),
+		[5.0 / 10.0, 0.0, 3.0 / 10.0, 1.0 / 10.0, 1.0 / 10.0],
+	);
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, GameState, RevealResult, new_game},
+	testimony::{ConfessorClaim, ScoutClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::{PlayerAction, RevealStrategy, predict};
+
+fn reveal(
+	game_state: &mut GameState,
+	number: usize,
+	archetype: GoodVillager,
+	testimony: Testimony,
+) {
+	game_state
+		.mutate(Action::TryReveal(RevealResult::new(
+			VillagerIndex::number(number),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(archetype),
+				Some(Expression::Leaf(testimony)),
+			)),
+		)))
+		.expect("Game state mutation failed");
+}
+
+fn scout_board(evils: [Minion; 2]) -> GameState {
+	new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Scout),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::Minion(evils[0].clone()),
+			VillagerArchetype::Minion(evils[1].clone()),
+		],
+		DrawStats::new(4, 0, 2, 0),
+		2,
+		false,
+	)
+	.expect("Invalid deck")
+}
+
+// both twinions are hidden among #4, #5 and #6 if the scout is good, the nearer one decides the claimed distance
+// with #4 vouched for, neither placement puts the nearest twinion 2 away, so the scout must be lying
+#[test]
+fn scout_claims_twinion() {
+	let mut game_state = scout_board([Minion::Twinion, Minion::Twinion]);
+
+	reveal(
+		&mut game_state,
+		1,
+		GoodVillager::Scout,
+		Testimony::Scout(ScoutClaim::new(
+			VillagerArchetype::Minion(Minion::Twinion),
+			2,
+		)),
+	);
+	reveal(
+		&mut game_state,
+		2,
+		GoodVillager::Confessor,
+		Testimony::Confess(ConfessorClaim::Good),
+	);
+	reveal(
+		&mut game_state,
+		3,
+		GoodVillager::Gemcrafter,
+		Testimony::Good(VillagerIndex::number(4)),
+	);
+
+	let log = log::logger();
+	let prediction = predict(&log, &game_state, RevealStrategy::Simple).expect("Prediction failed");
+	assert_eq!(
+		HashSet::from([PlayerAction::TryExecute(VillagerIndex::number(1))]),
+		prediction
+	);
+}
+
+// with #5 vouched for, the only placements left for the shaman are ones where the scout lies about it
+#[test]
+fn scout_claims_shaman() {
+	let mut game_state = scout_board([Minion::Shaman, Minion::Minion]);
+
+	reveal(
+		&mut game_state,
+		1,
+		GoodVillager::Scout,
+		Testimony::Scout(ScoutClaim::new(
+			VillagerArchetype::Minion(Minion::Shaman),
+			3,
+		)),
+	);
+	reveal(
+		&mut game_state,
+		2,
+		GoodVillager::Confessor,
+		Testimony::Confess(ConfessorClaim::Good),
+	);
+	reveal(
+		&mut game_state,
+		3,
+		GoodVillager::Gemcrafter,
+		Testimony::Good(VillagerIndex::number(5)),
+	);
+
+	let log = log::logger();
+	let prediction = predict(&log, &game_state, RevealStrategy::Simple).expect("Prediction failed");
+	assert_eq!(
+		HashSet::from([PlayerAction::TryExecute(VillagerIndex::number(1))]),
+		prediction
+	);
+}
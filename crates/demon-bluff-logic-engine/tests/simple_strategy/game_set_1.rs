@@ -27,7 +27,8 @@ pub fn game_0001() {
 		DrawStats::new(4, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	// reveal lover
 	println!("Prediction 1:");
@@ -110,7 +111,8 @@ pub fn game_0002() {
 		DrawStats::new(4, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	// reveal confessor (fake)
 	println!("Prediction 1:");
@@ -170,7 +172,8 @@ pub fn game_0003() {
 		DrawStats::new(4, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	// reveal enlightend
 	println!("Prediction 1:");
@@ -244,7 +247,8 @@ fn game_0004() {
 		DrawStats::new(4, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -323,7 +327,8 @@ fn game_0005() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -418,7 +423,8 @@ fn game_0006() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -511,7 +517,8 @@ fn game_0007() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -606,7 +613,8 @@ fn game_0008() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -699,7 +707,8 @@ fn game_0009() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -791,7 +800,8 @@ fn game_0010() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
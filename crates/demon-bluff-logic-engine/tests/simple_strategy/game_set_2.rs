@@ -29,7 +29,8 @@ fn game_0011() {
 		DrawStats::new(5, 0, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -97,7 +98,8 @@ fn game_0012() {
 		DrawStats::new(4, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -206,7 +208,8 @@ fn game_0013() {
 		DrawStats::new(5, 1, 2, 0),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -303,7 +306,8 @@ fn game_0014() {
 		DrawStats::new(4, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -400,7 +404,8 @@ fn game_0015() {
 		DrawStats::new(5, 1, 2, 0),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -494,7 +499,8 @@ fn game_0016() {
 		DrawStats::new(4, 1, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -577,7 +583,8 @@ fn game_0017() {
 		DrawStats::new(4, 1, 1, 0),
 		1,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -663,7 +670,8 @@ fn game_0018() {
 		DrawStats::new(6, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -793,7 +801,8 @@ fn game_0019() {
 		DrawStats::new(4, 2, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -910,7 +919,8 @@ fn game_0020() {
 		DrawStats::new(6, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
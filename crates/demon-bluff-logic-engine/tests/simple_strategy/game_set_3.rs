@@ -42,7 +42,8 @@ fn game_0021() {
 		DrawStats::new(5, 1, 2, 1),
 		3,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -171,7 +172,8 @@ fn game_0022() {
 		DrawStats::new(6, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -334,7 +336,8 @@ fn game_0023() {
 		DrawStats::new(6, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -473,7 +476,8 @@ fn game_0024() {
 		DrawStats::new(4, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -596,7 +600,8 @@ fn game_0025() {
 		DrawStats::new(5, 1, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -716,7 +721,8 @@ fn game_0026() {
 		DrawStats::new(5, 1, 1, 1),
 		3,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -844,7 +850,8 @@ fn game_0027() {
 		DrawStats::new(5, 2, 1, 1),
 		3,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -927,7 +934,8 @@ fn game_0028() {
 		DrawStats::new(5, 2, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -1097,7 +1105,8 @@ fn game_0029() {
 		DrawStats::new(5, 2, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -1251,7 +1260,8 @@ fn game_0030() {
 		DrawStats::new(5, 2, 1, 1),
 		2,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
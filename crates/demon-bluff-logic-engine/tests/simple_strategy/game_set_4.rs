@@ -42,7 +42,8 @@ fn game_0031() {
 		DrawStats::new(5, 1, 2, 1),
 		3,
 		false,
-	);
+	)
+	.expect("Invalid deck");
 
 	run_game(
 		&game_state,
@@ -150,22 +151,22 @@ fn game_0031() {
 				),
 			),
 			TestAction::TryExecute(KillAttempt::new(
-				VillagerIndex::number(7),
+				VillagerIndex::number(8),
 				Some(KillResult::Revealed(
-					KillData::new(None, false).expect("Bad kill data?"),
+					KillData::new(Some(VillagerArchetype::Minion(Minion::Counsellor)), false)
+						.expect("Bad kill data?"),
 				)),
 			)),
 			TestAction::TryExecute(KillAttempt::new(
-				VillagerIndex::number(6),
+				VillagerIndex::number(7),
 				Some(KillResult::Revealed(
-					KillData::new(Some(VillagerArchetype::Demon(Demon::Baa)), false)
-						.expect("Bad kill data?"),
+					KillData::new(None, false).expect("Bad kill data?"),
 				)),
 			)),
 			TestAction::TryExecute(KillAttempt::new(
-				VillagerIndex::number(8),
+				VillagerIndex::number(6),
 				Some(KillResult::Revealed(
-					KillData::new(Some(VillagerArchetype::Minion(Minion::Counsellor)), false)
+					KillData::new(Some(VillagerArchetype::Demon(Demon::Baa)), false)
 						.expect("Bad kill data?"),
 				)),
 			)),
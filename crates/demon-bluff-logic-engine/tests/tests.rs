@@ -1,4 +1,14 @@
 #![feature(once_cell_try_insert)]
 
+mod explanation;
+mod follow_testimony_strategy;
 mod helpers;
+mod information_gain_strategy;
+mod night_kill;
+mod planner;
+mod probabilities;
+mod regression_corpus;
+mod rule_sets;
+mod scout_claims;
 mod simple_strategy;
+mod unsat_core;
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use demon_bluff_gameplay_engine::{
+	Expression,
+	game_state::{Action, DrawStats, RevealResult, new_game},
+	testimony::{ConfessorClaim, RoleClaim, Testimony},
+	villager::{GoodVillager, Minion, VillagerArchetype, VillagerIndex, VillagerInstance},
+};
+use demon_bluff_logic_engine::{IndexTestimony, PredictionError, RevealStrategy, predict};
+
+// the gemcrafter vouches for the medium, who can only be lying with a single evil in play
+#[test]
+fn unsat_core_0001() {
+	let mut game_state = new_game(
+		vec![
+			VillagerArchetype::GoodVillager(GoodVillager::Lover),
+			VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+			VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+			VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+			VillagerArchetype::GoodVillager(GoodVillager::Medium),
+			VillagerArchetype::GoodVillager(GoodVillager::Judge),
+			VillagerArchetype::Minion(Minion::Minion),
+		],
+		DrawStats::new(5, 0, 1, 0),
+		1,
+		false,
+	)
+	.expect("Invalid deck");
+
+	let gemcrafter_claim = Testimony::Good(VillagerIndex(2));
+	let medium_claim = Testimony::Role(RoleClaim::new(
+		VillagerIndex(0),
+		VillagerArchetype::GoodVillager(GoodVillager::Hunter),
+	));
+	for reveal in [
+		RevealResult::new(
+			VillagerIndex(0),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Gemcrafter),
+				Some(Expression::Leaf(gemcrafter_claim.clone())),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(1),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Confessor),
+				Some(Expression::Leaf(Testimony::Confess(ConfessorClaim::Good))),
+			)),
+		),
+		RevealResult::new(
+			VillagerIndex(2),
+			Some(VillagerInstance::new(
+				VillagerArchetype::GoodVillager(GoodVillager::Medium),
+				Some(Expression::Leaf(medium_claim.clone())),
+			)),
+		),
+	] {
+		game_state
+			.mutate(Action::TryReveal(reveal))
+			.expect("Game state mutation failed");
+	}
+
+	let log = log::logger();
+	let Err(PredictionError::GameUnsolvable(core)) =
+		predict(&log, &game_state, RevealStrategy::Simple)
+	else {
+		panic!("The game should be unsolvable");
+	};
+
+	// the confessor has nothing to do with it
+	assert_eq!(
+		BTreeSet::from([
+			IndexTestimony::new(VillagerIndex(0), gemcrafter_claim),
+			IndexTestimony::new(VillagerIndex(2), medium_claim),
+		]),
+		core.testimonies
+	);
+	assert_eq!(game_state.draw_stats(), &core.game_draw_stats);
+	assert!(core.to_string().starts_with("Conflicting testimonies: "));
+}